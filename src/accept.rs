@@ -0,0 +1,435 @@
+/*!
+Provides parsing and generation of the HTTP `Accept-Language` header, as defined
+by [RFC 7231, section 5.3.5](https://tools.ietf.org/html/rfc7231#section-5.3.5).
+
+An [`AcceptLanguage`](struct.AcceptLanguage.html) value is a list of
+[`Preference`](struct.Preference.html)s, each a `LanguageRange` with a quality
+value. Parsing is tolerant, as headers are user-supplied and frequently malformed;
+entries that cannot be parsed are skipped rather than failing the whole header. To
+limit the cost of parsing hostile input, only the first 4096 bytes and the first
+32 entries of a header are considered; see `AcceptLanguage::parse_with_limits`.
+
+## Examples
+
+```
+use locale_types::accept::AcceptLanguage;
+
+let header = AcceptLanguage::parse("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5, de;q=0");
+let ranges: Vec<String> = header
+    .preferences()
+    .iter()
+    .map(|p| p.text().to_string())
+    .collect();
+assert_eq!(ranges, vec!["fr-CH", "fr", "en", "*"]);
+
+let locales = header.to_locale_strings();
+assert_eq!(locales[0].to_string(), "fr_CH");
+assert_eq!(locales.len(), 3);
+```
+
+```
+use locale_types::accept::AcceptLanguage;
+use locale_types::LocaleString;
+use std::str::FromStr;
+
+let locales: Vec<LocaleString> = ["en_US.UTF-8", "en", "sr_RS@latin"]
+    .iter()
+    .map(|s| LocaleString::from_str(s).unwrap())
+    .collect();
+let header = AcceptLanguage::from_locale_strings(&locales);
+assert_eq!(header.to_string(), "en-US, en;q=0.9, sr-Latn-RS;q=0.8");
+```
+*/
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::convert::to_language_tag;
+use crate::negotiate::LanguageRange;
+use crate::{LanguageTag, LocaleString};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A single entry in an `Accept-Language` header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Preference {
+    range: LanguageRange,
+    text: String,
+    quality: u16,
+}
+
+/// A parsed `Accept-Language` header, the entries are held in the order they
+/// appeared in the header.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AcceptLanguage {
+    entries: Vec<Preference>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The default maximum number of bytes of a header that will be parsed.
+pub const DEFAULT_MAX_LENGTH: usize = 4096;
+
+/// The default maximum number of entries of a header that will be parsed.
+pub const DEFAULT_MAX_ENTRIES: usize = 32;
+
+/// The maximum quality value, `1`, in thousandths.
+pub const MAX_QUALITY: u16 = 1000;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - Preference
+// ------------------------------------------------------------------------------------------------
+
+impl Preference {
+    /// Construct a new preference; `quality` is in thousandths, from 0 to 1000, and
+    /// is reduced to 1000 if it is greater.
+    pub fn new(range: LanguageRange, quality: u16) -> Self {
+        Preference {
+            text: range.to_string(),
+            range,
+            quality: quality.min(MAX_QUALITY),
+        }
+    }
+
+    /// Return the language range of this preference.
+    pub fn range(&self) -> &LanguageRange {
+        &self.range
+    }
+
+    /// Return the language range as it was written, retaining its case; ranges
+    /// are compared case-insensitively, but the case is kept when formatting.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Return the quality of this preference in thousandths, from 0 to 1000.
+    pub fn quality(&self) -> u16 {
+        self.quality
+    }
+
+    /// Return the quality of this preference as a value from 0.0 to 1.0.
+    pub fn q(&self) -> f32 {
+        f32::from(self.quality) / f32::from(MAX_QUALITY)
+    }
+
+    /// Returns `true` if the quality is 0, meaning the range is _not_ acceptable.
+    pub fn is_rejected(&self) -> bool {
+        self.quality == 0
+    }
+}
+
+impl Display for Preference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)?;
+        if self.quality < MAX_QUALITY {
+            let fraction = format!("{:03}", self.quality);
+            write!(f, ";q=0.{}", fraction.trim_end_matches('0'))?;
+            if self.quality == 0 {
+                write!(f, "0")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - AcceptLanguage
+// ------------------------------------------------------------------------------------------------
+
+const SEP_ENTRY: char = ',';
+const SEP_PARAMETER: char = ';';
+const QUALITY_PARAMETER: &str = "q";
+const OWS: [char; 2] = [' ', '\t'];
+
+impl AcceptLanguage {
+    /// Parse a header value using the default limits, skipping malformed entries.
+    pub fn parse(s: &str) -> Self {
+        Self::parse_with_limits(s, DEFAULT_MAX_LENGTH, DEFAULT_MAX_ENTRIES)
+    }
+
+    /// Parse a header value, skipping malformed entries, considering only the first
+    /// `max_length` bytes of `s` and the first `max_entries` well-formed entries.
+    /// An entry cut by the length limit is ignored.
+    pub fn parse_with_limits(s: &str, max_length: usize, max_entries: usize) -> Self {
+        let s = if s.len() > max_length {
+            let mut end = max_length;
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            // the cut is between entries if only optional whitespace precedes a separator.
+            if s[end..].trim_start_matches(OWS).starts_with(SEP_ENTRY) {
+                &s[..end]
+            } else {
+                match s[..end].rfind(SEP_ENTRY) {
+                    Some(index) => &s[..index],
+                    None => "",
+                }
+            }
+        } else {
+            s
+        };
+        AcceptLanguage {
+            entries: s
+                .split(SEP_ENTRY)
+                .filter_map(parse_entry)
+                .take(max_entries)
+                .collect(),
+        }
+    }
+
+    /// Construct a header from a list of locales, most preferred first; quality values
+    /// are assigned in descending order so that the order of the list is preserved.
+    pub fn from_locale_strings(locales: &[LocaleString]) -> Self {
+        let mut ranges: Vec<(LanguageRange, String)> = Vec::new();
+        for locale in locales {
+            let tag = to_language_tag(locale).into_value().to_string();
+            if let Ok(range) = LanguageRange::from_str(&tag) {
+                if ranges.iter().all(|(r, _)| *r != range) {
+                    ranges.push((range, tag));
+                }
+            }
+        }
+        let step = if ranges.len() <= 10 {
+            100
+        } else {
+            (900 / (ranges.len() - 1)).max(1) as u16
+        };
+        let mut header = AcceptLanguage::default();
+        let mut quality = MAX_QUALITY;
+        for (range, text) in ranges {
+            header.push(Preference {
+                range,
+                text,
+                quality,
+            });
+            quality = quality.saturating_sub(step).max(1);
+        }
+        header
+    }
+
+    /// Add a preference to the end of this header.
+    pub fn push(&mut self, preference: Preference) {
+        self.entries.push(preference)
+    }
+
+    /// Return all entries in the order they appeared in the header.
+    pub fn entries(&self) -> Vec<Preference> {
+        self.entries.clone()
+    }
+
+    /// Return the acceptable entries, those with a non-zero quality, ordered by
+    /// descending quality; entries with the same quality keep their header order.
+    pub fn preferences(&self) -> Vec<Preference> {
+        let mut preferences: Vec<Preference> = self
+            .entries
+            .iter()
+            .filter(|e| !e.is_rejected())
+            .cloned()
+            .collect();
+        preferences.sort_by_key(|p| std::cmp::Reverse(p.quality));
+        preferences
+    }
+
+    /// Return the ranges of the acceptable entries, as ordered by `preferences`;
+    /// suitable for use with the functions in the `negotiate` module.
+    pub fn ranges(&self) -> Vec<LanguageRange> {
+        self.preferences().into_iter().map(|p| p.range).collect()
+    }
+
+    /// Return the acceptable entries as locales, as ordered by `preferences`; the
+    /// wildcard, and any ranges that cannot be represented as a `LocaleString`, are
+    /// skipped.
+    pub fn to_locale_strings(&self) -> Vec<LocaleString> {
+        self.preferences()
+            .iter()
+            .filter(|p| !p.range.is_wildcard())
+            .filter_map(|p| LanguageTag::from_str(&p.text).ok())
+            .filter_map(|tag| LocaleString::try_from(&tag).ok())
+            .collect()
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Display for AcceptLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: Vec<String> = self.entries.iter().map(|e| e.to_string()).collect();
+        write!(f, "{}", entries.join(", "))
+    }
+}
+
+/// Parsing is tolerant and so never fails, see `AcceptLanguage::parse`.
+impl FromStr for AcceptLanguage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(AcceptLanguage::parse(s))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Parse a single `range *( ";" parameter )` entry, returning `None` if it is malformed.
+fn parse_entry(entry: &str) -> Option<Preference> {
+    let mut parts = entry.split(SEP_PARAMETER).map(str::trim);
+    let text = parts.next()?;
+    let range = LanguageRange::from_str(text).ok()?;
+    if !range.is_basic() {
+        return None;
+    }
+    let mut quality = MAX_QUALITY;
+    for parameter in parts {
+        let (name, value) = parameter.split_at(parameter.find('=')?);
+        if name.trim().eq_ignore_ascii_case(QUALITY_PARAMETER) {
+            quality = parse_quality(value[1..].trim())?;
+        }
+    }
+    Some(Preference {
+        range,
+        text: text.to_string(),
+        quality,
+    })
+}
+
+/// Parse an RFC 7231 `qvalue`, `0` to `1` with at most 3 decimal places.
+fn parse_quality(s: &str) -> Option<u16> {
+    let (whole, fraction) = match s.find('.') {
+        Some(index) => (&s[..index], &s[index + 1..]),
+        None => (s, ""),
+    };
+    if fraction.len() > 3 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let fraction: u16 = format!("{:0<3}", fraction).parse().ok()?;
+    match whole {
+        "0" => Some(fraction),
+        "1" if fraction == 0 => Some(MAX_QUALITY),
+        _ => None,
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn ranges(header: &AcceptLanguage) -> Vec<String> {
+        header
+            .preferences()
+            .iter()
+            .map(|p| p.text().to_string())
+            .collect()
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_parse_quality() {
+        assert_eq!(parse_quality("1"), Some(1000));
+        assert_eq!(parse_quality("1.000"), Some(1000));
+        assert_eq!(parse_quality("0.8"), Some(800));
+        assert_eq!(parse_quality("0.125"), Some(125));
+        assert_eq!(parse_quality("0"), Some(0));
+        assert_eq!(parse_quality("0."), Some(0));
+        assert_eq!(parse_quality("1.1"), None);
+        assert_eq!(parse_quality("0.1234"), None);
+        assert_eq!(parse_quality("2"), None);
+        assert_eq!(parse_quality("-0.5"), None);
+        assert_eq!(parse_quality(""), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let header = AcceptLanguage::parse("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+        assert_eq!(ranges(&header), vec!["fr-CH", "fr", "en", "*"]);
+        assert_eq!(header.entries()[1].quality(), 900);
+        assert_eq!(header.entries()[3].q(), 0.5);
+        assert_eq!(header.to_string(), "fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+    }
+
+    #[test]
+    fn test_ordering() {
+        let header = AcceptLanguage::parse("en;q=0.5,de,fr;q=0.5 ,es;Q=1,it;q=0");
+        assert_eq!(ranges(&header), vec!["de", "es", "en", "fr"]);
+        assert!(header.entries()[4].is_rejected());
+        assert_eq!(header.to_string(), "en;q=0.5, de, fr;q=0.5, es, it;q=0.0");
+    }
+
+    #[test]
+    fn test_malformed() {
+        let header =
+            AcceptLanguage::parse("en-US,,;q=0.5, fr;q=high, de-*-DE, 123, it ;level=1, es;q=0.3");
+        assert_eq!(ranges(&header), vec!["en-US", "it", "es"]);
+        assert!(AcceptLanguage::parse("").is_empty());
+        assert!(AcceptLanguage::parse(" , ; = ").is_empty());
+    }
+
+    #[test]
+    fn test_limits() {
+        let long = vec!["en"; 100].join(",");
+        assert_eq!(
+            AcceptLanguage::parse(&long).entries().len(),
+            DEFAULT_MAX_ENTRIES
+        );
+        let header = AcceptLanguage::parse_with_limits("en-US, fr-CA, de-DE", 10, 10);
+        assert_eq!(ranges(&header), vec!["en-US"]);
+        let header = AcceptLanguage::parse_with_limits("en-US, fr-CA, de-DE", 12, 10);
+        assert_eq!(ranges(&header), vec!["en-US", "fr-CA"]);
+        let header = AcceptLanguage::parse_with_limits("en-US,fr-CA", 5, 10);
+        assert_eq!(ranges(&header), vec!["en-US"]);
+        let header = AcceptLanguage::parse_with_limits("en-US , fr", 5, 10);
+        assert_eq!(ranges(&header), vec!["en-US"]);
+        let header = AcceptLanguage::parse_with_limits("en-US \t, fr", 6, 10);
+        assert_eq!(ranges(&header), vec!["en-US"]);
+        assert!(AcceptLanguage::parse_with_limits("en-US x, fr", 5, 10).is_empty());
+        assert!(AcceptLanguage::parse_with_limits("de-CH-1996", 5, 10).is_empty());
+        let header = AcceptLanguage::parse_with_limits("en-US, fr-CA, de-DE", 100, 2);
+        assert_eq!(ranges(&header), vec!["en-US", "fr-CA"]);
+    }
+
+    #[test]
+    fn test_locale_strings() {
+        let header =
+            AcceptLanguage::parse("sr-Latn-RS, x-private;q=0.9, *;q=0.1, de-CH-1996;q=0.5");
+        let locales: Vec<String> = header
+            .to_locale_strings()
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        assert_eq!(locales, vec!["sr_RS@latin", "de_CH@1996"]);
+
+        let locales: Vec<LocaleString> = (0..12)
+            .map(|i| LocaleString::from_str(&format!("e{}", (b'a' + i) as char)).unwrap())
+            .collect();
+        let header = AcceptLanguage::from_locale_strings(&locales);
+        let qualities: Vec<u16> = header.entries().iter().map(|e| e.quality()).collect();
+        assert_eq!(qualities[0], 1000);
+        assert!(qualities.windows(2).all(|pair| pair[0] > pair[1]));
+        assert_eq!(AcceptLanguage::parse(&header.to_string()), header);
+
+        // duplicates are not counted when choosing the step between quality values.
+        let repeated: Vec<LocaleString> = locales[..10]
+            .iter()
+            .flat_map(|l| vec![l.clone(), l.clone()])
+            .collect();
+        let header = AcceptLanguage::from_locale_strings(&repeated);
+        let qualities: Vec<u16> = header.entries().iter().map(|e| e.quality()).collect();
+        assert_eq!(qualities.len(), 10);
+        assert_eq!(qualities[9], 100);
+    }
+}
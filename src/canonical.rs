@@ -0,0 +1,357 @@
+/*!
+Provides canonicalization of locale identifiers that use deprecated or aliased values.
+
+Identifiers found in older data often use codes that have since been replaced, such
+as the language `iw` (now `he`), or the territory `YU` (now `RS`). The `canonicalize`
+operation on `LocaleString` and `Locale` rewrites these values using an embedded alias
+table, and returns a [`Canonicalized`](struct.Canonicalized.html) value which holds the
+canonical identifier and a [`Change`](struct.Change.html) for each value rewritten.
+
+The following values are rewritten:
+
+| Component   | Rewritten values                                    | Example                  |
+|-------------|-----------------------------------------------------|--------------------------|
+| language    | deprecated codes, and 3-character codes that have a 2-character equivalent | `iw` ⇒ `he`, `ger` ⇒ `de` |
+| language    | `no` (Norwegian), as Norwegian Bokmål               | `no_NO` ⇒ `nb_NO`        |
+| language    | `sh` (Serbo-Croatian), as Serbian in Latin script   | `sh_YU` ⇒ `sr_RS@latin`  |
+| territory   | deprecated codes, and UN M.49 codes for countries   | `YU` ⇒ `RS`, `840` ⇒ `US` |
+| modifier    | deprecated ISO 15924 script codes                   | `@Qaai` ⇒ `@Zinh`        |
+| code set    | aliases and non-display forms                       | `utf8` ⇒ `UTF-8`         |
+
+## Examples
+
+```
+use locale_types::{Locale, LocaleString};
+use locale_types::string::Component;
+use std::str::FromStr;
+
+let locale = LocaleString::from_str("sh_YU.utf8").unwrap();
+let canonical = locale.canonicalize();
+assert_eq!(canonical.value().to_string(), "sr_RS.UTF-8@latin");
+assert_eq!(canonical.changes().len(), 4);
+assert_eq!(canonical.changes()[1].component(), Component::Territory);
+assert_eq!(canonical.changes()[1].from(), Some("YU".to_string()));
+assert_eq!(canonical.changes()[1].to(), "RS");
+
+let locale = Locale::from_str("iw_IL").unwrap();
+assert_eq!(locale.canonicalize().value().to_string(), "he_IL");
+```
+*/
+
+use crate::id::LocaleIdentifier;
+use crate::string::{display_code_set, Component};
+use crate::{Locale, LocaleString};
+
+mod data;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A single value rewritten during canonicalization.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    component: Component,
+    from: Option<String>,
+    to: String,
+}
+
+/// The result of canonicalizing an identifier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canonicalized<T> {
+    value: T,
+    changes: Vec<Change>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - LocaleString and Locale
+// ------------------------------------------------------------------------------------------------
+
+impl LocaleString {
+    /// Return the canonical form of this identifier, replacing deprecated and aliased
+    /// language, territory, script and code set values, along with the changes made.
+    pub fn canonicalize(&self) -> Canonicalized<LocaleString> {
+        let mut changes: Vec<Change> = Vec::new();
+        let mut locale = self.clone();
+
+        let language = locale.language_code();
+        let mut canonical = alias(&data::LANGUAGES, &language)
+            .unwrap_or(&language)
+            .to_string();
+        let mut script: Option<&str> = None;
+        if let Some((_, replacement, modifier)) = LANGUAGE_SCRIPTS
+            .iter()
+            .find(|(from, _, _)| *from == canonical.as_str())
+        {
+            canonical = replacement.to_string();
+            script = Some(modifier);
+        }
+        if canonical != language {
+            locale = locale.with_language(canonical.clone()).unwrap();
+            changes.push(Change::new(Component::Language, Some(language), &canonical));
+        }
+
+        if let Some(territory) = locale.territory() {
+            if let Some(canonical) = alias(&data::TERRITORIES, &territory) {
+                locale = locale.with_territory(canonical.to_string()).unwrap();
+                changes.push(Change::new(
+                    Component::Territory,
+                    Some(territory),
+                    canonical,
+                ));
+            }
+        }
+
+        if let Some(code_set) = locale.code_set() {
+            let canonical = canonical_code_set(&code_set);
+            if canonical != code_set {
+                locale = locale.with_code_set(canonical.clone()).unwrap();
+                changes.push(Change::new(Component::CodeSet, Some(code_set), &canonical));
+            }
+        }
+
+        match (locale.modifier(), script) {
+            (None, Some(script)) => {
+                locale = locale.with_modifier(script.to_string()).unwrap();
+                changes.push(Change::new(Component::Modifier, None, script));
+            }
+            (Some(modifier), _) => {
+                if let Some(canonical) =
+                    locale.script_code().and_then(|code| alias(&SCRIPTS, &code))
+                {
+                    locale = locale.with_modifier(canonical.to_string()).unwrap();
+                    changes.push(Change::new(Component::Modifier, Some(modifier), canonical));
+                }
+            }
+            (None, None) => (),
+        }
+
+        Canonicalized {
+            value: locale,
+            changes,
+        }
+    }
+}
+
+impl Locale {
+    /// Return the canonical form of this locale; for the minimal locale only the code
+    /// set is canonicalized, and paths are returned unchanged.
+    pub fn canonicalize(&self) -> Canonicalized<Locale> {
+        match self {
+            Locale::POSIX {
+                code_set: Some(code_set),
+                modifier,
+            } => {
+                let canonical = canonical_code_set(code_set);
+                let changes = if &canonical != code_set {
+                    vec![Change::new(
+                        Component::CodeSet,
+                        Some(code_set.clone()),
+                        &canonical,
+                    )]
+                } else {
+                    Vec::new()
+                };
+                Canonicalized {
+                    value: Locale::POSIX {
+                        code_set: Some(canonical),
+                        modifier: modifier.clone(),
+                    },
+                    changes,
+                }
+            }
+            Locale::String(locale) => {
+                let canonical = locale.canonicalize();
+                Canonicalized {
+                    value: Locale::String(canonical.value),
+                    changes: canonical.changes,
+                }
+            }
+            _ => Canonicalized {
+                value: self.clone(),
+                changes: Vec::new(),
+            },
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - Change and Canonicalized
+// ------------------------------------------------------------------------------------------------
+
+impl Change {
+    fn new(component: Component, from: Option<String>, to: &str) -> Self {
+        Change {
+            component,
+            from,
+            to: to.to_string(),
+        }
+    }
+
+    /// Return the component of the identifier that was changed.
+    pub fn component(&self) -> Component {
+        self.component
+    }
+
+    /// Return the value that was replaced, or `None` if the value was added.
+    pub fn from(&self) -> Option<String> {
+        self.from.clone()
+    }
+
+    /// Return the replacement value.
+    pub fn to(&self) -> String {
+        self.to.clone()
+    }
+}
+
+impl<T> Canonicalized<T> {
+    /// Return a reference to the canonical value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Return the canonical value, discarding the list of changes.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Return the changes made to produce the canonical value.
+    pub fn changes(&self) -> Vec<Change> {
+        self.changes.clone()
+    }
+
+    /// Returns `true` if the original value was already canonical.
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+/// Language codes that are replaced by another language written in a specific script,
+/// expressed as a glibc script modifier.
+const LANGUAGE_SCRIPTS: [(&str, &str, &str); 1] = [("sh", "sr", "latin")];
+
+/// Deprecated ISO 15924 script codes and their replacements.
+const SCRIPTS: [(&str, &str); 1] = [("Qaai", "Zinh")];
+
+/// Common code set aliases that are not recognized by the glibc normalization.
+const CODE_SETS: [(&str, &str); 8] = [
+    ("latin1", "ISO-8859-1"),
+    ("latin2", "ISO-8859-2"),
+    ("latin3", "ISO-8859-3"),
+    ("latin4", "ISO-8859-4"),
+    ("latin5", "ISO-8859-9"),
+    ("latin6", "ISO-8859-10"),
+    ("latin9", "ISO-8859-15"),
+    ("latin10", "ISO-8859-16"),
+];
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn alias<'a>(table: &'a [(&'a str, &'a str)], value: &str) -> Option<&'a str> {
+    table
+        .binary_search_by(|(from, _)| (*from).cmp(value))
+        .ok()
+        .map(|index| table[index].1)
+}
+
+fn canonical_code_set(code_set: &str) -> String {
+    match CODE_SETS
+        .iter()
+        .find(|(from, _)| from.eq_ignore_ascii_case(code_set))
+    {
+        Some((_, to)) => to.to_string(),
+        None => display_code_set(code_set),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{data, Change};
+    use crate::string::Component;
+    use crate::{Locale, LocaleString, Modifier};
+
+    fn canonical(s: &str) -> String {
+        LocaleString::from_str(s)
+            .unwrap()
+            .canonicalize()
+            .value()
+            .to_string()
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_tables_are_sorted() {
+        assert!(data::LANGUAGES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(data::TERRITORIES
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_deprecated_values() {
+        assert_eq!(canonical("iw_IL"), "he_IL");
+        assert_eq!(canonical("in_ID"), "id_ID");
+        assert_eq!(canonical("ji"), "yi");
+        assert_eq!(canonical("no_NO"), "nb_NO");
+        assert_eq!(canonical("sh_YU"), "sr_RS@latin");
+        assert_eq!(canonical("sh_YU@cyrillic"), "sr_RS@cyrillic");
+        assert_eq!(canonical("ger_DE"), "de_DE");
+        assert_eq!(canonical("en_840"), "en_US");
+        assert_eq!(canonical("es_419"), "es_419");
+        assert_eq!(canonical("en_US@Qaai"), "en_US@Zinh");
+        assert_eq!(canonical("de_DE.latin9@euro"), "de_DE.ISO-8859-15@euro");
+    }
+
+    #[test]
+    fn test_changes() {
+        let locale = LocaleString::from_str("en_US.UTF-8").unwrap();
+        let canonical = locale.canonicalize();
+        assert!(canonical.is_unchanged());
+        assert_eq!(canonical.into_value(), locale);
+
+        let canonical = LocaleString::from_str("sh").unwrap().canonicalize();
+        assert_eq!(
+            canonical.changes(),
+            vec![
+                Change::new(Component::Language, Some("sh".to_string()), "sr"),
+                Change::new(Component::Modifier, None, "latin"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_locale() {
+        let canonical = Locale::from_str("C.latin1@euro").unwrap().canonicalize();
+        assert_eq!(
+            canonical.value(),
+            &Locale::POSIX {
+                code_set: Some("ISO-8859-1".to_string()),
+                modifier: Some(Modifier::from_str("euro").unwrap()),
+            }
+        );
+        assert_eq!(canonical.changes().len(), 1);
+        assert!(Locale::posix().canonicalize().is_unchanged());
+        let path = Locale::from_str("/usr/share/locale/iw_IL").unwrap();
+        assert!(path.canonicalize().is_unchanged());
+        assert_eq!(
+            Locale::from_str("ji_US.utf8")
+                .unwrap()
+                .canonicalize()
+                .value()
+                .to_string(),
+            "yi_US.UTF-8"
+        );
+    }
+}
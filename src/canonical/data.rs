@@ -0,0 +1,576 @@
+// Generated from the IANA Language Subtag Registry, the Debian iso-codes ISO 639-2
+// and ISO 3166-1 tables, and the CLDR language and territory aliases; do not edit
+// by hand.
+
+/// Deprecated, or non-preferred, language codes and their replacements.
+#[rustfmt::skip]
+pub(super) const LANGUAGES: [(&str, &str); 297] = [
+    ("aam", "aas"),
+    ("aar", "aa"),
+    ("abk", "ab"),
+    ("adp", "dz"),
+    ("afr", "af"),
+    ("aka", "ak"),
+    ("alb", "sq"),
+    ("amh", "am"),
+    ("ara", "ar"),
+    ("arg", "an"),
+    ("arm", "hy"),
+    ("asd", "snz"),
+    ("asm", "as"),
+    ("aue", "ktz"),
+    ("ava", "av"),
+    ("ave", "ae"),
+    ("aym", "ay"),
+    ("ayx", "nun"),
+    ("aze", "az"),
+    ("bak", "ba"),
+    ("bam", "bm"),
+    ("baq", "eu"),
+    ("bel", "be"),
+    ("ben", "bn"),
+    ("bgm", "bcg"),
+    ("bic", "bir"),
+    ("bih", "bh"),
+    ("bis", "bi"),
+    ("bjd", "drl"),
+    ("blg", "iba"),
+    ("bod", "bo"),
+    ("bos", "bs"),
+    ("bre", "br"),
+    ("bul", "bg"),
+    ("bur", "my"),
+    ("cat", "ca"),
+    ("ccq", "rki"),
+    ("ces", "cs"),
+    ("cha", "ch"),
+    ("che", "ce"),
+    ("chi", "zh"),
+    ("chu", "cu"),
+    ("chv", "cv"),
+    ("cjr", "mom"),
+    ("cka", "cmr"),
+    ("cmk", "xch"),
+    ("cor", "kw"),
+    ("cos", "co"),
+    ("coy", "pij"),
+    ("cqu", "quh"),
+    ("cre", "cr"),
+    ("cym", "cy"),
+    ("cze", "cs"),
+    ("dan", "da"),
+    ("deu", "de"),
+    ("dit", "dif"),
+    ("div", "dv"),
+    ("drh", "khk"),
+    ("drr", "kzk"),
+    ("drw", "prs"),
+    ("dut", "nl"),
+    ("dzo", "dz"),
+    ("ell", "el"),
+    ("eng", "en"),
+    ("epo", "eo"),
+    ("est", "et"),
+    ("eus", "eu"),
+    ("ewe", "ee"),
+    ("fao", "fo"),
+    ("fas", "fa"),
+    ("fij", "fj"),
+    ("fin", "fi"),
+    ("fra", "fr"),
+    ("fre", "fr"),
+    ("fry", "fy"),
+    ("ful", "ff"),
+    ("gav", "dev"),
+    ("geo", "ka"),
+    ("ger", "de"),
+    ("gfx", "vaj"),
+    ("ggn", "gvr"),
+    ("gla", "gd"),
+    ("gle", "ga"),
+    ("glg", "gl"),
+    ("gli", "kzk"),
+    ("glv", "gv"),
+    ("gre", "el"),
+    ("grn", "gn"),
+    ("gti", "nyc"),
+    ("guj", "gu"),
+    ("guv", "duz"),
+    ("hat", "ht"),
+    ("hau", "ha"),
+    ("heb", "he"),
+    ("her", "hz"),
+    ("hin", "hi"),
+    ("hmo", "ho"),
+    ("hrr", "jal"),
+    ("hrv", "hr"),
+    ("hun", "hu"),
+    ("hye", "hy"),
+    ("ibi", "opa"),
+    ("ibo", "ig"),
+    ("ice", "is"),
+    ("ido", "io"),
+    ("iii", "ii"),
+    ("iku", "iu"),
+    ("ile", "ie"),
+    ("ilw", "gal"),
+    ("in", "id"),
+    ("ina", "ia"),
+    ("ind", "id"),
+    ("ipk", "ik"),
+    ("isl", "is"),
+    ("ita", "it"),
+    ("iw", "he"),
+    ("jav", "jv"),
+    ("jeg", "oyb"),
+    ("ji", "yi"),
+    ("jpn", "ja"),
+    ("jw", "jv"),
+    ("kal", "kl"),
+    ("kan", "kn"),
+    ("kas", "ks"),
+    ("kat", "ka"),
+    ("kau", "kr"),
+    ("kaz", "kk"),
+    ("kgc", "tdf"),
+    ("kgh", "kml"),
+    ("khm", "km"),
+    ("kik", "ki"),
+    ("kin", "rw"),
+    ("kir", "ky"),
+    ("koj", "kwv"),
+    ("kom", "kv"),
+    ("kon", "kg"),
+    ("kor", "ko"),
+    ("krm", "bmf"),
+    ("ktr", "dtp"),
+    ("kua", "kj"),
+    ("kur", "ku"),
+    ("kvs", "gdj"),
+    ("kwq", "yam"),
+    ("kxe", "tvd"),
+    ("kxl", "kru"),
+    ("kzj", "dtp"),
+    ("kzt", "dtp"),
+    ("lao", "lo"),
+    ("lat", "la"),
+    ("lav", "lv"),
+    ("lii", "raq"),
+    ("lim", "li"),
+    ("lin", "ln"),
+    ("lit", "lt"),
+    ("llo", "ngt"),
+    ("lmm", "rmx"),
+    ("ltz", "lb"),
+    ("lub", "lu"),
+    ("lug", "lg"),
+    ("mac", "mk"),
+    ("mah", "mh"),
+    ("mal", "ml"),
+    ("mao", "mi"),
+    ("mar", "mr"),
+    ("may", "ms"),
+    ("meg", "cir"),
+    ("mkd", "mk"),
+    ("mlg", "mg"),
+    ("mlt", "mt"),
+    ("mo", "ro"),
+    ("mon", "mn"),
+    ("mri", "mi"),
+    ("msa", "ms"),
+    ("mst", "mry"),
+    ("mwj", "vaj"),
+    ("mya", "my"),
+    ("myd", "aog"),
+    ("myt", "mry"),
+    ("nad", "xny"),
+    ("nau", "na"),
+    ("nav", "nv"),
+    ("nbl", "nr"),
+    ("ncp", "kdz"),
+    ("nde", "nd"),
+    ("ndo", "ng"),
+    ("nep", "ne"),
+    ("nld", "nl"),
+    ("nno", "nn"),
+    ("nns", "nbr"),
+    ("nnx", "ngv"),
+    ("no", "nb"),
+    ("nob", "nb"),
+    ("nor", "nb"),
+    ("nts", "pij"),
+    ("nxu", "bpp"),
+    ("nya", "ny"),
+    ("oci", "oc"),
+    ("oji", "oj"),
+    ("ori", "or"),
+    ("orm", "om"),
+    ("oss", "os"),
+    ("oun", "vaj"),
+    ("pan", "pa"),
+    ("pat", "kxr"),
+    ("pcr", "adx"),
+    ("per", "fa"),
+    ("pli", "pi"),
+    ("pmc", "huw"),
+    ("pmu", "phr"),
+    ("pol", "pl"),
+    ("por", "pt"),
+    ("ppa", "bfy"),
+    ("ppr", "lcq"),
+    ("pry", "prt"),
+    ("pus", "ps"),
+    ("puz", "pub"),
+    ("que", "qu"),
+    ("roh", "rm"),
+    ("ron", "ro"),
+    ("rum", "ro"),
+    ("run", "rn"),
+    ("rus", "ru"),
+    ("sag", "sg"),
+    ("san", "sa"),
+    ("sca", "hle"),
+    ("sin", "si"),
+    ("skk", "oyb"),
+    ("slk", "sk"),
+    ("slo", "sk"),
+    ("slv", "sl"),
+    ("sme", "se"),
+    ("smo", "sm"),
+    ("sna", "sn"),
+    ("snd", "sd"),
+    ("som", "so"),
+    ("sot", "st"),
+    ("spa", "es"),
+    ("sqi", "sq"),
+    ("srd", "sc"),
+    ("srp", "sr"),
+    ("ssw", "ss"),
+    ("sun", "su"),
+    ("swa", "sw"),
+    ("swe", "sv"),
+    ("tah", "ty"),
+    ("tam", "ta"),
+    ("tat", "tt"),
+    ("tdu", "dtp"),
+    ("tel", "te"),
+    ("tgk", "tg"),
+    ("tgl", "tl"),
+    ("tha", "th"),
+    ("thc", "tpo"),
+    ("thw", "ola"),
+    ("thx", "oyb"),
+    ("tib", "bo"),
+    ("tie", "ras"),
+    ("tir", "ti"),
+    ("tkk", "twm"),
+    ("tlw", "weo"),
+    ("tmp", "tyj"),
+    ("tne", "kak"),
+    ("tnf", "prs"),
+    ("ton", "to"),
+    ("tsf", "taj"),
+    ("tsn", "tn"),
+    ("tso", "ts"),
+    ("tuk", "tk"),
+    ("tur", "tr"),
+    ("twi", "tw"),
+    ("uig", "ug"),
+    ("ukr", "uk"),
+    ("uok", "ema"),
+    ("urd", "ur"),
+    ("uzb", "uz"),
+    ("ven", "ve"),
+    ("vie", "vi"),
+    ("vol", "vo"),
+    ("wel", "cy"),
+    ("wln", "wa"),
+    ("wol", "wo"),
+    ("xba", "cax"),
+    ("xho", "xh"),
+    ("xia", "acn"),
+    ("xkh", "waw"),
+    ("xrq", "dmw"),
+    ("ybd", "rki"),
+    ("yid", "yi"),
+    ("yma", "lrr"),
+    ("ymt", "mtm"),
+    ("yor", "yo"),
+    ("yos", "zom"),
+    ("yuu", "yug"),
+    ("zha", "za"),
+    ("zho", "zh"),
+    ("zir", "scv"),
+    ("zul", "zu"),
+];
+
+/// Deprecated territory codes, and numeric country codes, and their replacements.
+#[rustfmt::skip]
+pub(super) const TERRITORIES: [(&str, &str); 266] = [
+    ("004", "AF"),
+    ("008", "AL"),
+    ("010", "AQ"),
+    ("012", "DZ"),
+    ("016", "AS"),
+    ("020", "AD"),
+    ("024", "AO"),
+    ("028", "AG"),
+    ("031", "AZ"),
+    ("032", "AR"),
+    ("036", "AU"),
+    ("040", "AT"),
+    ("044", "BS"),
+    ("048", "BH"),
+    ("050", "BD"),
+    ("051", "AM"),
+    ("052", "BB"),
+    ("056", "BE"),
+    ("060", "BM"),
+    ("064", "BT"),
+    ("068", "BO"),
+    ("070", "BA"),
+    ("072", "BW"),
+    ("074", "BV"),
+    ("076", "BR"),
+    ("084", "BZ"),
+    ("086", "IO"),
+    ("090", "SB"),
+    ("092", "VG"),
+    ("096", "BN"),
+    ("100", "BG"),
+    ("104", "MM"),
+    ("108", "BI"),
+    ("112", "BY"),
+    ("116", "KH"),
+    ("120", "CM"),
+    ("124", "CA"),
+    ("132", "CV"),
+    ("136", "KY"),
+    ("140", "CF"),
+    ("144", "LK"),
+    ("148", "TD"),
+    ("152", "CL"),
+    ("156", "CN"),
+    ("158", "TW"),
+    ("162", "CX"),
+    ("166", "CC"),
+    ("170", "CO"),
+    ("174", "KM"),
+    ("175", "YT"),
+    ("178", "CG"),
+    ("180", "CD"),
+    ("184", "CK"),
+    ("188", "CR"),
+    ("191", "HR"),
+    ("192", "CU"),
+    ("196", "CY"),
+    ("203", "CZ"),
+    ("204", "BJ"),
+    ("208", "DK"),
+    ("212", "DM"),
+    ("214", "DO"),
+    ("218", "EC"),
+    ("222", "SV"),
+    ("226", "GQ"),
+    ("231", "ET"),
+    ("232", "ER"),
+    ("233", "EE"),
+    ("234", "FO"),
+    ("238", "FK"),
+    ("239", "GS"),
+    ("242", "FJ"),
+    ("246", "FI"),
+    ("248", "AX"),
+    ("250", "FR"),
+    ("254", "GF"),
+    ("258", "PF"),
+    ("260", "TF"),
+    ("262", "DJ"),
+    ("266", "GA"),
+    ("268", "GE"),
+    ("270", "GM"),
+    ("275", "PS"),
+    ("276", "DE"),
+    ("288", "GH"),
+    ("292", "GI"),
+    ("296", "KI"),
+    ("300", "GR"),
+    ("304", "GL"),
+    ("308", "GD"),
+    ("312", "GP"),
+    ("316", "GU"),
+    ("320", "GT"),
+    ("324", "GN"),
+    ("328", "GY"),
+    ("332", "HT"),
+    ("334", "HM"),
+    ("336", "VA"),
+    ("340", "HN"),
+    ("344", "HK"),
+    ("348", "HU"),
+    ("352", "IS"),
+    ("356", "IN"),
+    ("360", "ID"),
+    ("364", "IR"),
+    ("368", "IQ"),
+    ("372", "IE"),
+    ("376", "IL"),
+    ("380", "IT"),
+    ("384", "CI"),
+    ("388", "JM"),
+    ("392", "JP"),
+    ("398", "KZ"),
+    ("400", "JO"),
+    ("404", "KE"),
+    ("408", "KP"),
+    ("410", "KR"),
+    ("414", "KW"),
+    ("417", "KG"),
+    ("418", "LA"),
+    ("422", "LB"),
+    ("426", "LS"),
+    ("428", "LV"),
+    ("430", "LR"),
+    ("434", "LY"),
+    ("438", "LI"),
+    ("440", "LT"),
+    ("442", "LU"),
+    ("446", "MO"),
+    ("450", "MG"),
+    ("454", "MW"),
+    ("458", "MY"),
+    ("462", "MV"),
+    ("466", "ML"),
+    ("470", "MT"),
+    ("474", "MQ"),
+    ("478", "MR"),
+    ("480", "MU"),
+    ("484", "MX"),
+    ("492", "MC"),
+    ("496", "MN"),
+    ("498", "MD"),
+    ("499", "ME"),
+    ("500", "MS"),
+    ("504", "MA"),
+    ("508", "MZ"),
+    ("512", "OM"),
+    ("516", "NA"),
+    ("520", "NR"),
+    ("524", "NP"),
+    ("528", "NL"),
+    ("531", "CW"),
+    ("533", "AW"),
+    ("534", "SX"),
+    ("535", "BQ"),
+    ("540", "NC"),
+    ("548", "VU"),
+    ("554", "NZ"),
+    ("558", "NI"),
+    ("562", "NE"),
+    ("566", "NG"),
+    ("570", "NU"),
+    ("574", "NF"),
+    ("578", "NO"),
+    ("580", "MP"),
+    ("581", "UM"),
+    ("583", "FM"),
+    ("584", "MH"),
+    ("585", "PW"),
+    ("586", "PK"),
+    ("591", "PA"),
+    ("598", "PG"),
+    ("600", "PY"),
+    ("604", "PE"),
+    ("608", "PH"),
+    ("612", "PN"),
+    ("616", "PL"),
+    ("620", "PT"),
+    ("624", "GW"),
+    ("626", "TL"),
+    ("630", "PR"),
+    ("634", "QA"),
+    ("638", "RE"),
+    ("642", "RO"),
+    ("643", "RU"),
+    ("646", "RW"),
+    ("652", "BL"),
+    ("654", "SH"),
+    ("659", "KN"),
+    ("660", "AI"),
+    ("662", "LC"),
+    ("663", "MF"),
+    ("666", "PM"),
+    ("670", "VC"),
+    ("674", "SM"),
+    ("678", "ST"),
+    ("682", "SA"),
+    ("686", "SN"),
+    ("688", "RS"),
+    ("690", "SC"),
+    ("694", "SL"),
+    ("702", "SG"),
+    ("703", "SK"),
+    ("704", "VN"),
+    ("705", "SI"),
+    ("706", "SO"),
+    ("710", "ZA"),
+    ("716", "ZW"),
+    ("724", "ES"),
+    ("728", "SS"),
+    ("729", "SD"),
+    ("732", "EH"),
+    ("740", "SR"),
+    ("744", "SJ"),
+    ("748", "SZ"),
+    ("752", "SE"),
+    ("756", "CH"),
+    ("760", "SY"),
+    ("762", "TJ"),
+    ("764", "TH"),
+    ("768", "TG"),
+    ("772", "TK"),
+    ("776", "TO"),
+    ("780", "TT"),
+    ("784", "AE"),
+    ("788", "TN"),
+    ("792", "TR"),
+    ("795", "TM"),
+    ("796", "TC"),
+    ("798", "TV"),
+    ("800", "UG"),
+    ("804", "UA"),
+    ("807", "MK"),
+    ("818", "EG"),
+    ("826", "GB"),
+    ("831", "GG"),
+    ("832", "JE"),
+    ("833", "IM"),
+    ("834", "TZ"),
+    ("840", "US"),
+    ("850", "VI"),
+    ("854", "BF"),
+    ("858", "UY"),
+    ("860", "UZ"),
+    ("862", "VE"),
+    ("876", "WF"),
+    ("882", "WS"),
+    ("887", "YE"),
+    ("894", "ZM"),
+    ("AN", "CW"),
+    ("BU", "MM"),
+    ("CS", "RS"),
+    ("DD", "DE"),
+    ("DY", "BJ"),
+    ("FX", "FR"),
+    ("HV", "BF"),
+    ("NH", "VU"),
+    ("NT", "SA"),
+    ("RH", "ZW"),
+    ("SU", "RU"),
+    ("TP", "TL"),
+    ("UK", "GB"),
+    ("VD", "VN"),
+    ("YD", "YE"),
+    ("YU", "RS"),
+    ("ZR", "CD"),
+];
@@ -0,0 +1,356 @@
+/*!
+Provides the `LocaleCategory` enumeration, naming the categories of a locale that
+may be set independently.
+
+## Standards
+
+POSIX defines the categories `LC_CTYPE`, `LC_COLLATE`, `LC_MONETARY`, `LC_NUMERIC`,
+`LC_TIME`, and `LC_MESSAGES`, as well as `LC_ALL` which denotes all of them. The GNU C
+library adds the categories `LC_PAPER`, `LC_NAME`, `LC_ADDRESS`, `LC_TELEPHONE`,
+`LC_MEASUREMENT`, and `LC_IDENTIFICATION`.
+
+Each category has an environment variable of the same name, and an integer value
+used by `setlocale(3)`; the integer values are platform specific, and a platform may
+not support every category.
+
+## Examples
+
+```
+use locale_types::category::LocaleCategory;
+use std::str::FromStr;
+
+let category = LocaleCategory::from_str("LC_MESSAGES").unwrap();
+assert_eq!(category, LocaleCategory::Messages);
+assert_eq!(category.env_name(), "LC_MESSAGES");
+assert!(category.is_posix());
+assert!(!LocaleCategory::Paper.is_posix());
+assert_eq!(LocaleCategory::from_str("messages"), Ok(LocaleCategory::Messages));
+```
+*/
+
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::LocaleError;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The categories of a locale, as defined by POSIX and the GNU C library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LocaleCategory {
+    /// `LC_ALL`, all categories together.
+    All,
+    /// `LC_CTYPE`, character classification and case conversion.
+    CharacterType,
+    /// `LC_NUMERIC`, non-monetary numeric formats.
+    Numeric,
+    /// `LC_TIME`, date and time formats.
+    Time,
+    /// `LC_COLLATE`, string collation order.
+    Collate,
+    /// `LC_MONETARY`, monetary formats.
+    Monetary,
+    /// `LC_MESSAGES`, the language of messages, and yes/no responses.
+    Messages,
+    /// `LC_PAPER`, the default paper size (GNU).
+    Paper,
+    /// `LC_NAME`, formats of personal names (GNU).
+    Name,
+    /// `LC_ADDRESS`, formats of postal addresses (GNU).
+    Address,
+    /// `LC_TELEPHONE`, formats of telephone numbers (GNU).
+    Telephone,
+    /// `LC_MEASUREMENT`, the default measurement system (GNU).
+    Measurement,
+    /// `LC_IDENTIFICATION`, metadata describing the locale itself (GNU).
+    Identification,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - LocaleCategory
+// ------------------------------------------------------------------------------------------------
+
+const ENV_PREFIX: &str = "LC_";
+
+impl LocaleCategory {
+    /// Return all the categories, `All` first followed by the individual categories
+    /// in the order defined by glibc.
+    pub fn all() -> &'static [LocaleCategory] {
+        &ALL_CATEGORIES
+    }
+
+    /// Return the individual categories, that is all except `All`.
+    pub fn individual() -> &'static [LocaleCategory] {
+        &ALL_CATEGORIES[1..]
+    }
+
+    /// Return the name of the environment variable for this category, which is
+    /// also the name of the C constant, for example `LC_CTYPE`.
+    pub fn env_name(&self) -> &'static str {
+        match self {
+            LocaleCategory::All => "LC_ALL",
+            LocaleCategory::CharacterType => "LC_CTYPE",
+            LocaleCategory::Numeric => "LC_NUMERIC",
+            LocaleCategory::Time => "LC_TIME",
+            LocaleCategory::Collate => "LC_COLLATE",
+            LocaleCategory::Monetary => "LC_MONETARY",
+            LocaleCategory::Messages => "LC_MESSAGES",
+            LocaleCategory::Paper => "LC_PAPER",
+            LocaleCategory::Name => "LC_NAME",
+            LocaleCategory::Address => "LC_ADDRESS",
+            LocaleCategory::Telephone => "LC_TELEPHONE",
+            LocaleCategory::Measurement => "LC_MEASUREMENT",
+            LocaleCategory::Identification => "LC_IDENTIFICATION",
+        }
+    }
+
+    /// Returns `true` if this category is defined by POSIX, rather than being a
+    /// GNU extension.
+    pub fn is_posix(&self) -> bool {
+        matches!(
+            self,
+            LocaleCategory::All
+                | LocaleCategory::CharacterType
+                | LocaleCategory::Numeric
+                | LocaleCategory::Time
+                | LocaleCategory::Collate
+                | LocaleCategory::Monetary
+                | LocaleCategory::Messages
+        )
+    }
+
+    /// Return the value of the C constant for this category on the current platform,
+    /// as passed to `setlocale(3)`, or `None` if the platform does not support it.
+    pub fn platform_value(&self) -> Option<i32> {
+        platform::value(*self)
+    }
+
+    /// Return the category with the value `value` of the C constant on the current
+    /// platform, or `None` if it is not a known category.
+    pub fn from_platform_value(value: i32) -> Option<Self> {
+        ALL_CATEGORIES
+            .iter()
+            .find(|category| category.platform_value() == Some(value))
+            .cloned()
+    }
+}
+
+impl Display for LocaleCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.env_name())
+    }
+}
+
+/// Parse a category from its environment variable name; the `LC_` prefix is
+/// optional and the name is not case-sensitive, so that `LC_CTYPE`, `ctype` and
+/// `Ctype` are all accepted. Unknown names return `LocaleError::UnsetCategory`.
+impl FromStr for LocaleCategory {
+    type Err = LocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = if s.len() > ENV_PREFIX.len()
+            && s.get(..ENV_PREFIX.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(ENV_PREFIX))
+        {
+            &s[ENV_PREFIX.len()..]
+        } else {
+            s
+        };
+        ALL_CATEGORIES
+            .iter()
+            .find(|category| category.env_name()[ENV_PREFIX.len()..].eq_ignore_ascii_case(name))
+            .cloned()
+            .ok_or(LocaleError::UnsetCategory)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const ALL_CATEGORIES: [LocaleCategory; 13] = [
+    LocaleCategory::All,
+    LocaleCategory::CharacterType,
+    LocaleCategory::Numeric,
+    LocaleCategory::Time,
+    LocaleCategory::Collate,
+    LocaleCategory::Monetary,
+    LocaleCategory::Messages,
+    LocaleCategory::Paper,
+    LocaleCategory::Name,
+    LocaleCategory::Address,
+    LocaleCategory::Telephone,
+    LocaleCategory::Measurement,
+    LocaleCategory::Identification,
+];
+
+// ------------------------------------------------------------------------------------------------
+// Private Modules
+// ------------------------------------------------------------------------------------------------
+
+/// Values from glibc `<locale.h>`, also used by Android's bionic; musl uses the same
+/// values, but only has the POSIX categories.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod platform {
+    use super::LocaleCategory;
+
+    pub(super) fn value(category: LocaleCategory) -> Option<i32> {
+        if cfg!(target_env = "musl") && !category.is_posix() {
+            return None;
+        }
+        Some(match category {
+            LocaleCategory::CharacterType => 0,
+            LocaleCategory::Numeric => 1,
+            LocaleCategory::Time => 2,
+            LocaleCategory::Collate => 3,
+            LocaleCategory::Monetary => 4,
+            LocaleCategory::Messages => 5,
+            LocaleCategory::All => 6,
+            LocaleCategory::Paper => 7,
+            LocaleCategory::Name => 8,
+            LocaleCategory::Address => 9,
+            LocaleCategory::Telephone => 10,
+            LocaleCategory::Measurement => 11,
+            LocaleCategory::Identification => 12,
+        })
+    }
+}
+
+/// Values from the BSD-derived `<locale.h>`, which only has the POSIX categories.
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod platform {
+    use super::LocaleCategory;
+
+    pub(super) fn value(category: LocaleCategory) -> Option<i32> {
+        match category {
+            LocaleCategory::All => Some(0),
+            LocaleCategory::Collate => Some(1),
+            LocaleCategory::CharacterType => Some(2),
+            LocaleCategory::Monetary => Some(3),
+            LocaleCategory::Numeric => Some(4),
+            LocaleCategory::Time => Some(5),
+            LocaleCategory::Messages => Some(6),
+            _ => None,
+        }
+    }
+}
+
+/// Values from the Microsoft C runtime `<locale.h>`, which has no `LC_MESSAGES`.
+#[cfg(windows)]
+mod platform {
+    use super::LocaleCategory;
+
+    pub(super) fn value(category: LocaleCategory) -> Option<i32> {
+        match category {
+            LocaleCategory::All => Some(0),
+            LocaleCategory::Collate => Some(1),
+            LocaleCategory::CharacterType => Some(2),
+            LocaleCategory::Monetary => Some(3),
+            LocaleCategory::Numeric => Some(4),
+            LocaleCategory::Time => Some(5),
+            _ => None,
+        }
+    }
+}
+
+/// The values of other platforms are not known.
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    windows
+)))]
+mod platform {
+    use super::LocaleCategory;
+
+    pub(super) fn value(_category: LocaleCategory) -> Option<i32> {
+        None
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_round_trip() {
+        for category in LocaleCategory::all() {
+            let name = category.to_string();
+            assert!(name.starts_with(ENV_PREFIX));
+            assert_eq!(LocaleCategory::from_str(&name), Ok(*category));
+        }
+        assert_eq!(LocaleCategory::individual().len(), 12);
+        assert!(!LocaleCategory::individual().contains(&LocaleCategory::All));
+    }
+
+    #[test]
+    fn test_parse_forms() {
+        assert_eq!(
+            LocaleCategory::from_str("lc_ctype"),
+            Ok(LocaleCategory::CharacterType)
+        );
+        assert_eq!(
+            LocaleCategory::from_str("CTYPE"),
+            Ok(LocaleCategory::CharacterType)
+        );
+        assert_eq!(
+            LocaleCategory::from_str("Identification"),
+            Ok(LocaleCategory::Identification)
+        );
+        assert_eq!(
+            LocaleCategory::from_str("LC_"),
+            Err(LocaleError::UnsetCategory)
+        );
+        assert_eq!(
+            LocaleCategory::from_str("LC_COLOR"),
+            Err(LocaleError::UnsetCategory)
+        );
+        for s in &["éé", "é", "LC_é", "lcé"] {
+            assert_eq!(LocaleCategory::from_str(s), Err(LocaleError::UnsetCategory));
+        }
+        assert_eq!(
+            LocaleCategory::from_str(""),
+            Err(LocaleError::UnsetCategory)
+        );
+    }
+
+    #[test]
+    fn test_platform_values() {
+        for category in LocaleCategory::all() {
+            if let Some(value) = category.platform_value() {
+                assert_eq!(LocaleCategory::from_platform_value(value), Some(*category));
+            }
+        }
+        assert_eq!(LocaleCategory::from_platform_value(-1), None);
+    }
+
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    #[test]
+    fn test_glibc_values() {
+        assert_eq!(LocaleCategory::All.platform_value(), Some(6));
+        assert_eq!(LocaleCategory::Messages.platform_value(), Some(5));
+        assert_eq!(LocaleCategory::Identification.platform_value(), Some(12));
+    }
+}
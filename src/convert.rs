@@ -0,0 +1,360 @@
+/*!
+Provides conversions between the POSIX `LocaleString` and the BCP 47 `LanguageTag`.
+
+The two forms overlap but are not equivalent; a `LocaleString` carries a code set
+which has no BCP 47 equivalent, and the POSIX modifier is free-form while BCP 47
+uses structured script, variant and extension subtags. The conversion functions
+in this module therefore return a [`Conversion`](struct.Conversion.html) which
+holds the converted value, any code set carried alongside it, and a list of every
+piece of information that could not be represented in the target form.
+
+The POSIX modifier is mapped as follows:
+
+| POSIX modifier    | BCP 47 subtag              | Example                               |
+|-------------------|----------------------------|---------------------------------------|
+| `@latin`          | script `Latn`              | `sr_RS@latin` ⇔ `sr-Latn-RS`          |
+| `@cyrillic`       | script `Cyrl`              | `uz_UZ@cyrillic` ⇔ `uz-Cyrl-UZ`       |
+| `@devanagari`     | script `Deva`              | `ks_IN@devanagari` ⇔ `ks-Deva-IN`     |
+| `@Hant`, ...      | script, if registered      | `zh_TW@Hant` ⇔ `zh-Hant-TW`           |
+| `@valencia`, ...  | a single variant           | `ca_ES@valencia` ⇔ `ca-ES-valencia`   |
+| `@euro`, ...      | dropped, reported as lost  | `de_DE@euro` ⇒ `de-DE`                |
+
+Script codes are only recognized in their registered title case form, any other
+modifier that is not a valid variant subtag is reported as lost. A modifier is also
+reported as lost when the conversion back would spell it differently, for example
+`en_US@Latn` converts to `en-Latn-US`, which converts back to `en_US@latin`.
+
+## Examples
+
+```
+use locale_types::convert::{to_language_tag, Loss};
+use locale_types::LocaleString;
+use std::str::FromStr;
+
+let locale = LocaleString::from_str("sr_RS.UTF-8@latin").unwrap();
+let conversion = to_language_tag(&locale);
+assert_eq!(conversion.value().to_string(), "sr-Latn-RS");
+assert_eq!(conversion.code_set(), Some("UTF-8".to_string()));
+assert!(conversion.is_lossless());
+
+let locale = LocaleString::from_str("de_DE@euro").unwrap();
+let conversion = to_language_tag(&locale);
+assert_eq!(conversion.value().to_string(), "de-DE");
+assert_eq!(conversion.lost(), vec![Loss::Modifier("euro".to_string())]);
+```
+
+*/
+use std::convert::TryFrom;
+
+use crate::id::LocaleIdentifier;
+use crate::string::SCRIPT_MODIFIERS;
+use crate::tag::{Extension, LanguageTag};
+use crate::{LocaleError, LocaleResult, LocaleString};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A piece of information that could not be represented in the target form
+/// of a conversion.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Loss {
+    /// A POSIX modifier that has no BCP 47 equivalent, such as `euro`, or that
+    /// used the `key=value` form.
+    Modifier(String),
+    /// BCP 47 extended language subtags, which have no POSIX equivalent.
+    ExtendedLanguages(Vec<String>),
+    /// A territory or region that could not be represented in the target form.
+    Territory(String),
+    /// BCP 47 variant subtags that could not be represented as a POSIX modifier.
+    Variants(Vec<String>),
+    /// BCP 47 extensions, which have no POSIX equivalent.
+    Extensions(Vec<Extension>),
+    /// BCP 47 private use subtags, which have no POSIX equivalent.
+    PrivateUse(Vec<String>),
+}
+
+/// The result of converting between a `LocaleString` and a `LanguageTag`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion<T> {
+    value: T,
+    code_set: Option<String>,
+    lost: Vec<Loss>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Convert a `LocaleString` into a `LanguageTag`; the code set, if any, is
+/// carried separately in the returned `Conversion`.
+pub fn to_language_tag(locale: &LocaleString) -> Conversion<LanguageTag> {
+    let mut lost: Vec<Loss> = Vec::new();
+    let mut tag = LanguageTag::new(locale.language_code()).unwrap();
+
+    if let Some(territory) = locale.territory() {
+        match tag.with_territory(territory.clone()) {
+            Ok(with_territory) => tag = with_territory,
+            Err(_) => lost.push(Loss::Territory(territory)),
+        }
+    }
+
+    if let Some(modifier) = locale.modifier() {
+        let converted = if DROPPED_MODIFIERS.contains(&modifier.as_str()) {
+            None
+        } else if let Some((_, script)) = SCRIPT_MODIFIERS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&modifier))
+        {
+            tag.with_script(script.to_string()).ok()
+        } else {
+            tag.with_modifier(modifier.clone()).ok()
+        };
+        match converted {
+            Some(with_modifier) => {
+                if posix_modifier(&with_modifier) != Some(modifier.clone()) {
+                    lost.push(Loss::Modifier(modifier));
+                }
+                tag = with_modifier;
+            }
+            None => lost.push(Loss::Modifier(modifier)),
+        }
+    }
+
+    Conversion {
+        value: tag,
+        code_set: locale.code_set(),
+        lost,
+    }
+}
+
+/// Convert a `LanguageTag` into a `LocaleString`. This will fail for grandfathered
+/// and private use tags, or tags whose language is not a 2 or 3-character code.
+pub fn to_locale_string(tag: &LanguageTag) -> LocaleResult<Conversion<LocaleString>> {
+    if tag.is_grandfathered() || tag.is_private_use() {
+        return Err(LocaleError::Unsupported);
+    }
+    let mut lost: Vec<Loss> = Vec::new();
+    let mut locale = LocaleString::new(tag.language_code())?;
+
+    if !tag.extended_languages().is_empty() {
+        lost.push(Loss::ExtendedLanguages(tag.extended_languages()));
+    }
+
+    if let Some(territory) = tag.territory() {
+        match locale.with_territory(territory.clone()) {
+            Ok(with_territory) => locale = with_territory,
+            Err(_) => lost.push(Loss::Territory(territory)),
+        }
+    }
+
+    let variants = tag.variants();
+    if let Some(modifier) = posix_modifier(tag) {
+        locale = locale.with_modifier(modifier)?;
+        if tag.script().is_some() && !variants.is_empty() {
+            lost.push(Loss::Variants(variants));
+        }
+    } else if !variants.is_empty() {
+        lost.push(Loss::Variants(variants));
+    }
+
+    if !tag.extensions().is_empty() {
+        lost.push(Loss::Extensions(tag.extensions()));
+    }
+    if !tag.private_use().is_empty() {
+        lost.push(Loss::PrivateUse(tag.private_use()));
+    }
+
+    Ok(Conversion {
+        value: locale,
+        code_set: None,
+        lost,
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - Conversion
+// ------------------------------------------------------------------------------------------------
+
+impl<T> Conversion<T> {
+    /// Return a reference to the converted value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Return the converted value, discarding any other information.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Return the code set carried alongside the converted value, if any.
+    pub fn code_set(&self) -> Option<String> {
+        self.code_set.clone()
+    }
+
+    /// Return the information that could not be represented in the converted value.
+    pub fn lost(&self) -> Vec<Loss> {
+        self.lost.clone()
+    }
+
+    /// Returns `true` if no information was lost, and so the conversion may be
+    /// reversed to produce the original value.
+    pub fn is_lossless(&self) -> bool {
+        self.lost.is_empty()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - From/TryFrom
+// ------------------------------------------------------------------------------------------------
+
+/// Lossy conversion, use `to_language_tag` to retrieve the code set and any lost information.
+impl From<&LocaleString> for LanguageTag {
+    fn from(locale: &LocaleString) -> Self {
+        to_language_tag(locale).into_value()
+    }
+}
+
+/// Lossy conversion, use `to_locale_string` to retrieve any lost information.
+impl TryFrom<&LanguageTag> for LocaleString {
+    type Error = LocaleError;
+
+    fn try_from(tag: &LanguageTag) -> Result<Self, Self::Error> {
+        Ok(to_locale_string(tag)?.into_value())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+/// The POSIX modifier for the script, or else the single variant, of `tag`.
+fn posix_modifier(tag: &LanguageTag) -> Option<String> {
+    let variants = tag.variants();
+    if let Some(script) = tag.script() {
+        match SCRIPT_MODIFIERS.iter().find(|(_, code)| *code == script) {
+            Some((name, _)) => Some(name.to_string()),
+            None => Some(script),
+        }
+    } else if variants.len() == 1 {
+        Some(variants[0].clone())
+    } else {
+        None
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+/// glibc modifier names that have no meaning in BCP 47.
+const DROPPED_MODIFIERS: [&str; 1] = ["euro"];
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use super::{to_language_tag, to_locale_string, Loss};
+    use crate::{LanguageTag, LocaleError, LocaleIdentifier, LocaleString};
+
+    fn round_trip(from: &str, to: &str) {
+        let locale = LocaleString::from_str(from).unwrap();
+        let conversion = to_language_tag(&locale);
+        assert!(conversion.is_lossless());
+        assert_eq!(conversion.value().to_string(), to);
+        let reversed = to_locale_string(conversion.value()).unwrap();
+        assert!(reversed.is_lossless());
+        let reversed = match conversion.code_set() {
+            Some(code_set) => reversed.value().with_code_set(code_set).unwrap(),
+            None => reversed.into_value(),
+        };
+        assert_eq!(reversed.to_string(), from);
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_round_trips() {
+        round_trip("en", "en");
+        round_trip("en_US", "en-US");
+        round_trip("en_US.UTF-8", "en-US");
+        round_trip("sr_RS.UTF-8@latin", "sr-Latn-RS");
+        round_trip("uz_UZ@cyrillic", "uz-Cyrl-UZ");
+        round_trip("ca_ES.UTF-8@valencia", "ca-ES-valencia");
+        round_trip("es_419.UTF-8", "es-419");
+    }
+
+    #[test]
+    fn test_script_code_modifier() {
+        round_trip("zh_TW@Hant", "zh-Hant-TW");
+        let locale = LocaleString::from_str("en_US@Latn").unwrap();
+        assert_eq!(LanguageTag::from(&locale).to_string(), "en-Latn-US");
+        let tag = LanguageTag::from_str("zh-Hant-TW").unwrap();
+        assert_eq!(
+            LocaleString::try_from(&tag).unwrap().to_string(),
+            "zh_TW@Hant"
+        );
+    }
+
+    #[test]
+    fn test_euro_dropped() {
+        let locale = LocaleString::from_str("de_DE.ISO-8859-15@euro").unwrap();
+        let conversion = to_language_tag(&locale);
+        assert_eq!(conversion.value().to_string(), "de-DE");
+        assert_eq!(conversion.code_set(), Some("ISO-8859-15".to_string()));
+        assert_eq!(conversion.lost(), vec![Loss::Modifier("euro".to_string())]);
+    }
+
+    #[test]
+    fn test_modifier_losses() {
+        for (from, to) in &[
+            ("en_US@test", "en-US"),
+            ("en_US@Test", "en-US"),
+            ("de_DE@Euro", "de-DE"),
+            ("en_US@collation=pinyin", "en-US"),
+            ("en_US@Latn", "en-Latn-US"),
+            ("sr_RS@Latin", "sr-Latn-RS"),
+            ("ca_ES@Valencia", "ca-ES-valencia"),
+        ] {
+            let locale = LocaleString::from_str(from).unwrap();
+            let conversion = to_language_tag(&locale);
+            assert_eq!(conversion.value().to_string(), *to);
+            assert_eq!(
+                conversion.lost(),
+                vec![Loss::Modifier(locale.modifier().unwrap())],
+                "{}",
+                from
+            );
+        }
+        let locale = LocaleString::from_str("en_US@Latn").unwrap();
+        let reversed = to_locale_string(to_language_tag(&locale).value()).unwrap();
+        assert_eq!(reversed.value().to_string(), "en_US@latin");
+    }
+
+    #[test]
+    fn test_tag_losses() {
+        let tag = LanguageTag::from_str("sl-rozaj-biske-u-co-phonebk-x-private").unwrap();
+        let conversion = to_locale_string(&tag).unwrap();
+        assert_eq!(conversion.value().to_string(), "sl");
+        assert!(!conversion.is_lossless());
+        let lost = conversion.lost();
+        assert_eq!(lost.len(), 3);
+        assert_eq!(
+            lost[0],
+            Loss::Variants(vec!["rozaj".to_string(), "biske".to_string()])
+        );
+        assert_eq!(lost[2], Loss::PrivateUse(vec!["private".to_string()]));
+    }
+
+    #[test]
+    fn test_unsupported_tags() {
+        let tag = LanguageTag::from_str("i-klingon").unwrap();
+        assert_eq!(LocaleString::try_from(&tag), Err(LocaleError::Unsupported));
+        let tag = LanguageTag::from_str("x-private").unwrap();
+        assert_eq!(LocaleString::try_from(&tag), Err(LocaleError::Unsupported));
+    }
+}
@@ -0,0 +1,434 @@
+/*!
+Provides resolution of the effective locale for each category from the environment
+variables defined by POSIX.
+
+## Standards
+
+POSIX defines the following precedence when determining the locale for a category:
+
+1. If `LC_ALL` is set, and not empty, its value is used for all categories.
+2. Otherwise, if the variable for the category, such as `LC_TIME`, is set, and not
+   empty, its value is used.
+3. Otherwise, if `LANG` is set, and not empty, its value is used.
+4. Otherwise, the minimal `POSIX` locale is used.
+
+The environment is read through the `Environment` trait, implemented for the
+process environment by `ProcessEnvironment` and for any `HashMap` of names to values,
+so that resolution may be tested without modifying the process environment.
+
+## GNU `LANGUAGE`
+
+GNU gettext also consults the `LANGUAGE` variable when looking up messages; it holds
+a colon-separated priority list of locales, such as `pt_BR:pt:en`, and takes
+precedence over the `LC_MESSAGES` category. It is ignored when `LC_MESSAGES` resolves
+to the minimal locale, so that `LC_ALL=C` disables translation regardless of
+`LANGUAGE`. The `message_locales` function applies these rules.
+
+## Examples
+
+```
+use locale_types::env::{resolve, LocaleSource};
+use locale_types::{Locale, LocaleCategory};
+use std::collections::HashMap;
+
+let mut env = HashMap::new();
+env.insert("LANG", "en_US.UTF-8");
+env.insert("LC_TIME", "en_GB.UTF-8");
+env.insert("LC_NUMERIC", "");
+
+let time = resolve(&env, LocaleCategory::Time).unwrap();
+assert_eq!(time.locale().to_string(), "en_GB.UTF-8");
+assert_eq!(time.source(), LocaleSource::Category(LocaleCategory::Time));
+
+let numeric = resolve(&env, LocaleCategory::Numeric).unwrap();
+assert_eq!(numeric.locale().to_string(), "en_US.UTF-8");
+assert_eq!(numeric.source().variable(), Some("LANG"));
+
+env.insert("LC_ALL", "C");
+let time = resolve(&env, LocaleCategory::Time).unwrap();
+assert_eq!(time.locale(), &Locale::posix());
+assert_eq!(time.source(), LocaleSource::All);
+```
+
+Determining the priority list for message lookup:
+
+```
+use locale_types::env::message_locales;
+use std::collections::HashMap;
+
+let mut env = HashMap::new();
+env.insert("LANG", "pt_PT.UTF-8");
+env.insert("LANGUAGE", "pt_BR:pt:en");
+
+let locales: Vec<String> = message_locales(&env)
+    .unwrap()
+    .iter()
+    .map(|l| l.to_string())
+    .collect();
+assert_eq!(locales, vec!["pt_BR", "pt", "en", "pt_PT.UTF-8"]);
+
+env.insert("LC_ALL", "C");
+assert_eq!(message_locales(&env).unwrap().len(), 1);
+```
+
+Using the process environment, whose values may not be valid locales:
+
+```no_run
+use locale_types::env::{resolve, ProcessEnvironment};
+use locale_types::{Locale, LocaleCategory};
+
+let collation = match resolve(&ProcessEnvironment, LocaleCategory::Collate) {
+    Ok(effective) => effective.locale().clone(),
+    Err(_) => Locale::posix(),
+};
+```
+*/
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::str::FromStr;
+
+use crate::category::LocaleCategory;
+use crate::locale::Locale;
+use crate::LocaleResult;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A source of environment variables.
+pub trait Environment {
+    /// Return the value of the variable `name`, or `None` if it is not set, or
+    /// its value is not valid Unicode.
+    fn var(&self, name: &str) -> Option<String>;
+}
+
+/// The environment of the current process, read with `std::env::var`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessEnvironment;
+
+/// The environment variable that determined the effective locale of a category.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LocaleSource {
+    /// The `LC_ALL` variable, which overrides all others.
+    All,
+    /// The variable named for the category, such as `LC_TIME`.
+    Category(LocaleCategory),
+    /// The `LANG` variable.
+    Lang,
+    /// No variable was set, the minimal `POSIX` locale is used.
+    Default,
+}
+
+/// The effective locale of a category, and where it came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveLocale {
+    category: LocaleCategory,
+    locale: Locale,
+    source: LocaleSource,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The name of the environment variable holding the default locale for all categories.
+pub const LANG: &str = "LANG";
+
+/// The name of the GNU environment variable holding the priority list of locales
+/// for message lookup.
+pub const LANGUAGE: &str = "LANGUAGE";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Return the effective locale of `category` in the environment `env`.
+///
+/// For `LocaleCategory::All` only `LC_ALL` and `LANG` are considered. An error is
+/// returned if the variable that takes precedence has a value that cannot be parsed
+/// as a `Locale`; as with `setlocale(3)`, variables of lower precedence are not
+/// consulted in this case.
+pub fn resolve<E: Environment>(env: &E, category: LocaleCategory) -> LocaleResult<EffectiveLocale> {
+    let (source, value) = if let Some(value) = non_empty_var(env, LocaleCategory::All.env_name()) {
+        (LocaleSource::All, value)
+    } else if let Some(value) = non_empty_var(env, category.env_name()) {
+        (LocaleSource::Category(category), value)
+    } else if let Some(value) = non_empty_var(env, LANG) {
+        (LocaleSource::Lang, value)
+    } else {
+        return Ok(EffectiveLocale {
+            category,
+            locale: Locale::posix(),
+            source: LocaleSource::Default,
+        });
+    };
+    Ok(EffectiveLocale {
+        category,
+        locale: Locale::from_str(&value)?,
+        source,
+    })
+}
+
+/// Return the effective locale of each individual category, that is all except
+/// `LocaleCategory::All`, in the environment `env`.
+pub fn resolve_all<E: Environment>(env: &E) -> LocaleResult<Vec<EffectiveLocale>> {
+    LocaleCategory::individual()
+        .iter()
+        .map(|category| resolve(env, *category))
+        .collect()
+}
+
+/// Parse the value of the GNU `LANGUAGE` variable, a colon-separated list of
+/// locales in priority order. As with gettext, empty entries and those that cannot be
+/// parsed are ignored, as are repeated entries.
+pub fn parse_language_list(s: &str) -> Vec<Locale> {
+    let mut locales: Vec<Locale> = Vec::new();
+    for locale in s
+        .split(SEP_LANGUAGE)
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| Locale::from_str(entry).ok())
+    {
+        if !locales.contains(&locale) {
+            locales.push(locale);
+        }
+    }
+    locales
+}
+
+/// Return the priority list of locales for message lookup in the environment `env`.
+///
+/// If the effective locale of `LocaleCategory::Messages` is the minimal locale the list
+/// holds only that locale. Otherwise the list holds the locales from `LANGUAGE`, if
+/// any, followed by the effective `LC_MESSAGES` locale if it is not already present.
+/// An error is returned only if the effective `LC_MESSAGES` locale cannot be parsed.
+pub fn message_locales<E: Environment>(env: &E) -> LocaleResult<Vec<Locale>> {
+    let messages = resolve(env, LocaleCategory::Messages)?.into_locale();
+    if messages.is_posix() {
+        return Ok(vec![messages]);
+    }
+    let mut locales = non_empty_var(env, LANGUAGE)
+        .map(|value| parse_language_list(&value))
+        .unwrap_or_default();
+    if !locales.contains(&messages) {
+        locales.push(messages);
+    }
+    Ok(locales)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - Environment
+// ------------------------------------------------------------------------------------------------
+
+impl Environment for ProcessEnvironment {
+    fn var(&self, name: &str) -> Option<String> {
+        std::env::var(name).ok()
+    }
+}
+
+impl<K, V> Environment for HashMap<K, V>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: AsRef<str>,
+{
+    fn var(&self, name: &str) -> Option<String> {
+        self.get(name).map(|value| value.as_ref().to_string())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - LocaleSource
+// ------------------------------------------------------------------------------------------------
+
+impl LocaleSource {
+    /// Return the name of the environment variable, or `None` for `Default`.
+    pub fn variable(&self) -> Option<&'static str> {
+        match self {
+            LocaleSource::All => Some(LocaleCategory::All.env_name()),
+            LocaleSource::Category(category) => Some(category.env_name()),
+            LocaleSource::Lang => Some(LANG),
+            LocaleSource::Default => None,
+        }
+    }
+}
+
+impl Display for LocaleSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.variable() {
+            Some(variable) => write!(f, "{}", variable),
+            None => write!(f, "default"),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - EffectiveLocale
+// ------------------------------------------------------------------------------------------------
+
+impl EffectiveLocale {
+    /// Return the category this locale applies to.
+    pub fn category(&self) -> LocaleCategory {
+        self.category
+    }
+
+    /// Return the effective locale.
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Return the effective locale, consuming this value.
+    pub fn into_locale(self) -> Locale {
+        self.locale
+    }
+
+    /// Return the environment variable the locale was taken from.
+    pub fn source(&self) -> LocaleSource {
+        self.source
+    }
+}
+
+/// Formats as `LC_TIME=en_GB.UTF-8 (from LC_ALL)`.
+impl Display for EffectiveLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}={} (from {})",
+            self.category, self.locale, self.source
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const SEP_LANGUAGE: char = ':';
+
+fn non_empty_var<E: Environment>(env: &E, name: &str) -> Option<String> {
+    env.var(name).filter(|value| !value.is_empty())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LocaleError;
+
+    fn env(vars: &[(&'static str, &'static str)]) -> HashMap<&'static str, &'static str> {
+        vars.iter().cloned().collect()
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_default() {
+        let effective = resolve(&env(&[]), LocaleCategory::Collate).unwrap();
+        assert_eq!(effective.locale(), &Locale::posix());
+        assert_eq!(effective.source(), LocaleSource::Default);
+        assert_eq!(effective.to_string(), "LC_COLLATE=C (from default)");
+    }
+
+    #[test]
+    fn test_precedence() {
+        let vars = env(&[
+            ("LANG", "de_DE.UTF-8"),
+            ("LC_MONETARY", "de_CH.UTF-8"),
+            ("LC_PAPER", "en_US"),
+        ]);
+        let all = resolve_all(&vars).unwrap();
+        assert_eq!(all.len(), 12);
+        for effective in all {
+            let (expected, source) = match effective.category() {
+                LocaleCategory::Monetary => (
+                    "de_CH.UTF-8",
+                    LocaleSource::Category(LocaleCategory::Monetary),
+                ),
+                LocaleCategory::Paper => ("en_US", LocaleSource::Category(LocaleCategory::Paper)),
+                _ => ("de_DE.UTF-8", LocaleSource::Lang),
+            };
+            assert_eq!(effective.locale().to_string(), expected);
+            assert_eq!(effective.source(), source);
+        }
+    }
+
+    #[test]
+    fn test_all_overrides() {
+        let vars = env(&[
+            ("LANG", "de_DE"),
+            ("LC_CTYPE", "fr_FR"),
+            ("LC_ALL", "/usr/lib/locale/x"),
+        ]);
+        let effective = resolve(&vars, LocaleCategory::CharacterType).unwrap();
+        assert_eq!(effective.locale().to_string(), "/usr/lib/locale/x");
+        assert_eq!(effective.source().variable(), Some("LC_ALL"));
+        let effective = resolve(&vars, LocaleCategory::All).unwrap();
+        assert_eq!(effective.source(), LocaleSource::All);
+    }
+
+    #[test]
+    fn test_empty_ignored() {
+        let vars = env(&[("LC_ALL", ""), ("LC_TIME", ""), ("LANG", "C.UTF-8")]);
+        let effective = resolve(&vars, LocaleCategory::Time).unwrap();
+        assert_eq!(effective.locale().to_string(), "C.UTF-8");
+        assert_eq!(effective.source(), LocaleSource::Lang);
+    }
+
+    #[test]
+    fn test_invalid_value() {
+        let vars = env(&[("LC_TIME", "en_US.#"), ("LANG", "C")]);
+        match resolve(&vars, LocaleCategory::Time) {
+            Err(LocaleError::Parse(_)) => (),
+            result => panic!("expected a parse error, not {:?}", result),
+        }
+        assert!(resolve(&vars, LocaleCategory::Numeric).is_ok());
+    }
+
+    #[test]
+    fn test_parse_language_list() {
+        let locales: Vec<String> = parse_language_list("pt_BR::pt:en_US.#:en:pt")
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        assert_eq!(locales, vec!["pt_BR", "pt", "en"]);
+        assert!(parse_language_list("").is_empty());
+        assert!(parse_language_list(":::").is_empty());
+    }
+
+    #[test]
+    fn test_message_locales() {
+        let to_strings = |locales: Vec<Locale>| -> Vec<String> {
+            locales.iter().map(|l| l.to_string()).collect()
+        };
+
+        let vars = env(&[]);
+        assert_eq!(to_strings(message_locales(&vars).unwrap()), vec!["C"]);
+
+        let vars = env(&[("LANG", "de_DE"), ("LANGUAGE", "")]);
+        assert_eq!(to_strings(message_locales(&vars).unwrap()), vec!["de_DE"]);
+
+        let vars = env(&[
+            ("LANG", "de_DE"),
+            ("LC_MESSAGES", "fr_FR"),
+            ("LANGUAGE", "it:fr_FR"),
+        ]);
+        assert_eq!(
+            to_strings(message_locales(&vars).unwrap()),
+            vec!["it", "fr_FR"]
+        );
+
+        let vars = env(&[
+            ("LANG", "de_DE"),
+            ("LC_MESSAGES", "C.UTF-8"),
+            ("LANGUAGE", "it"),
+        ]);
+        assert_eq!(to_strings(message_locales(&vars).unwrap()), vec!["C.UTF-8"]);
+
+        let vars = env(&[("LANG", "C"), ("LANGUAGE", "it")]);
+        assert_eq!(to_strings(message_locales(&vars).unwrap()), vec!["C"]);
+    }
+}
@@ -10,14 +10,39 @@ A locale identifier is comprised of, at least, the following components:
 5. a `modifier` string (commonly used to identify the script) ...
 
 */
+#[cfg(any(feature = "languages", feature = "territories", feature = "charsets"))]
+use crate::LocaleError;
 use crate::LocaleResult;
-use std::collections::HashMap;
 use std::fmt::Display;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+/// The order in which `key=value` pairs are written into a modifier by
+/// `LocaleIdentifier::with_modifiers_ordered`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModifierOrder {
+    /// Pairs are sorted by key, this is the canonical form and is independent of
+    /// the iteration order of the provided collection.
+    Sorted,
+    /// Pairs are written in the iteration order of the provided collection, use
+    /// with ordered inputs such as slices, vectors or a `BTreeMap`.
+    Insertion,
+}
+
+/// Implemented for the item types of collections that may be passed to
+/// `LocaleIdentifier::with_modifiers`; this includes owned or borrowed
+/// `(key, value)` tuples, and so `HashMap`, `BTreeMap`, slices and vectors
+/// of pairs, and arbitrary iterators over pairs.
+pub trait ModifierPair {
+    /// Return the key of this pair as a string.
+    fn key(&self) -> String;
+
+    /// Return the value of this pair as a string.
+    fn value(&self) -> String;
+}
+
 /// Common trait for different kinds of locale identifier.
 pub trait LocaleIdentifier
 where
@@ -29,20 +54,75 @@ where
     /// Return a new identifier based on `self` with a new language code.
     fn with_language(&self, language_code: String) -> LocaleResult<Self>;
 
+    /// Construct a new identifier with the given language code only, as `new`
+    /// but also rejecting well-formed codes that are not in the ISO 639 registry.
+    #[cfg(feature = "languages")]
+    fn new_strict(language_code: String) -> LocaleResult<Self> {
+        if !crate::registry::languages::is_known(&language_code) {
+            return Err(LocaleError::InvalidLanguageCode);
+        }
+        Self::new(language_code)
+    }
+
+    /// Return a new identifier based on `self` with a new language code, as
+    /// `with_language` but also rejecting codes that are not in the ISO 639 registry.
+    #[cfg(feature = "languages")]
+    fn with_language_strict(&self, language_code: String) -> LocaleResult<Self> {
+        if !crate::registry::languages::is_known(&language_code) {
+            return Err(LocaleError::InvalidLanguageCode);
+        }
+        self.with_language(language_code)
+    }
+
     /// Return a new identifier based on `self` with a new territory code.
     fn with_territory(&self, territory: String) -> LocaleResult<Self>;
 
+    /// Return a new identifier based on `self` with a new territory code, as
+    /// `with_territory` but also rejecting codes that are not assigned in ISO 3166-1,
+    /// or are not UN M.49 macro-regions; use `registry::territories::suggest` to
+    /// find a replacement for reserved codes such as `UK`.
+    #[cfg(feature = "territories")]
+    fn with_territory_strict(&self, territory: String) -> LocaleResult<Self> {
+        if !crate::registry::territories::is_known(&territory) {
+            return Err(LocaleError::InvalidTerritoryCode);
+        }
+        self.with_territory(territory)
+    }
+
     /// Return a new identifier based on `self` with a new code_set code.
     fn with_code_set(&self, code_set: String) -> LocaleResult<Self>;
 
+    /// Return a new identifier based on `self` with a new code set, as `with_code_set`
+    /// but rejecting names that are not registered character sets, and replacing
+    /// aliases such as `utf8` with the preferred name, `UTF-8`.
+    #[cfg(feature = "charsets")]
+    fn with_code_set_strict(&self, code_set: String) -> LocaleResult<Self> {
+        match crate::registry::charsets::canonical_name(&code_set) {
+            Some(name) => self.with_code_set(name.to_string()),
+            None => Err(LocaleError::InvalidCodeSet),
+        }
+    }
+
     /// Return a new identifier based on `self` with a new modifier string.
     fn with_modifier(&self, modifier: String) -> LocaleResult<Self>;
 
-    /// Return a new identifier based on `self` with a new modifier string.
-    fn with_modifiers<K, V>(&self, modifiers: HashMap<K, V>) -> LocaleResult<Self>
+    /// Return a new identifier based on `self` with a new modifier constructed
+    /// from `key=value` pairs. The pairs are sorted by key, so that the resulting
+    /// identifier is the same regardless of the iteration order of `modifiers`.
+    fn with_modifiers<I>(&self, modifiers: I) -> LocaleResult<Self>
     where
-        K: Display,
-        V: Display;
+        I: IntoIterator,
+        I::Item: ModifierPair,
+    {
+        self.with_modifiers_ordered(modifiers, ModifierOrder::Sorted)
+    }
+
+    /// Return a new identifier based on `self` with a new modifier constructed
+    /// from `key=value` pairs, ordered according to `order`.
+    fn with_modifiers_ordered<I>(&self, modifiers: I, order: ModifierOrder) -> LocaleResult<Self>
+    where
+        I: IntoIterator,
+        I::Item: ModifierPair;
 
     /// Return the current language code.
     fn language_code(&self) -> String;
@@ -56,3 +136,35 @@ where
     /// Return the current modifier string.
     fn modifier(&self) -> Option<String>;
 }
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<K, V> ModifierPair for (K, V)
+where
+    K: Display,
+    V: Display,
+{
+    fn key(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn value(&self) -> String {
+        self.1.to_string()
+    }
+}
+
+impl<K, V> ModifierPair for &(K, V)
+where
+    K: Display,
+    V: Display,
+{
+    fn key(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn value(&self) -> String {
+        self.1.to_string()
+    }
+}
@@ -5,7 +5,12 @@ This crate provides a [`Locale`](locale/enum.Locale.html) enumeration,
 [`LocaleIdentifier`](id/trait.LocaleIdentifier.html) trait, and a
 [`LocaleString`](string/struct.LocaleString.html) structure are provided that
 may be used to parse and construct locale identifiers in a
-standards-conformant manner.
+standards-conformant manner. The [`LanguageTag`](tag/struct.LanguageTag.html)
+structure also implements `LocaleIdentifier` for IETF BCP 47 language tags.
+
+The [`registry`](registry/index.html) module provides optional registries of
+standard codes, each enabled by a cargo feature; for example the `languages`
+feature enables validation of language codes against ISO 639.
 
 ## Example
 
@@ -28,8 +33,9 @@ println!("{}", locale);
     rust_2018_idioms
 )]
 
-#[macro_use]
-extern crate lazy_static;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -52,15 +58,71 @@ pub enum LocaleError {
     UnknownLocale,
     /// Locale category not set/or supported
     UnsetCategory,
-    /// Operating system could not set the specified locale
-    OSError,
+    /// Operating system could not set the specified locale, the string
+    /// describes the underlying reason.
+    OSError(String),
     /// The operation you tried to perform was not supported.
     Unsupported,
+    /// The provided string could not be parsed, the `ParseError` describes
+    /// where and why.
+    Parse(ParseError),
 }
 
 /// Common result type for functions in this crate.
 pub type LocaleResult<T> = Result<T, LocaleError>;
 
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for LocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocaleError::InvalidLocaleString => write!(f, "the locale string was badly formatted"),
+            LocaleError::InvalidLanguageCode => {
+                write!(f, "the language code was not valid, or was unknown")
+            }
+            LocaleError::InvalidTerritoryCode => {
+                write!(f, "the territory code was not valid, or was unknown")
+            }
+            LocaleError::InvalidCodeSet => {
+                write!(f, "the code set name was not valid, or was unknown")
+            }
+            LocaleError::InvalidModifier => {
+                write!(f, "the modifier string was not valid, or was unknown")
+            }
+            LocaleError::UnknownLocale => write!(f, "the locale was unknown"),
+            LocaleError::UnsetCategory => {
+                write!(f, "the locale category was not set, or is not supported")
+            }
+            LocaleError::OSError(reason) => {
+                write!(
+                    f,
+                    "the operating system could not set the locale: {}",
+                    reason
+                )
+            }
+            LocaleError::Unsupported => write!(f, "the operation is not supported"),
+            LocaleError::Parse(e) => write!(f, "the locale string could not be parsed: {}", e),
+        }
+    }
+}
+
+impl Error for LocaleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LocaleError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for LocaleError {
+    fn from(e: ParseError) -> Self {
+        LocaleError::Parse(e)
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Modules
 // ------------------------------------------------------------------------------------------------
@@ -69,7 +131,40 @@ pub mod id;
 pub use id::LocaleIdentifier;
 
 pub mod string;
-pub use string::LocaleString;
+pub use string::{LocaleString, ParseError, TerritoryKind};
+
+pub mod modifier;
+pub use modifier::Modifier;
+
+pub mod tag;
+pub use tag::LanguageTag;
+
+pub mod convert;
+
+pub mod canonical;
+
+pub mod negotiate;
+
+pub mod accept;
+
+#[cfg(feature = "language-matching")]
+pub mod matcher;
+
+#[cfg(feature = "likely-subtags")]
+pub mod likely;
 
 pub mod locale;
 pub use locale::Locale;
+
+pub mod category;
+pub use category::LocaleCategory;
+
+pub mod env;
+
+pub mod settings;
+pub use settings::LocaleSettings;
+
+#[cfg(all(feature = "os", target_os = "linux"))]
+pub mod os;
+
+pub mod registry;
@@ -0,0 +1,354 @@
+/*!
+Provides the CLDR _add likely subtags_ and _remove likely subtags_ operations,
+enabled by the `likely-subtags` feature.
+
+The [`LikelySubtags`](trait.LikelySubtags.html) trait is implemented for both
+`LanguageTag` and `LocaleString`. Adding likely subtags, `maximize`, fills in the
+most likely script and region for an identifier, and removing them, `minimize`,
+produces the shortest identifier that maximizes to the same value. The data is an
+embedded snapshot of the CLDR `likelySubtags` table, see `CLDR_VERSION`.
+
+A `LocaleString` carries its script in the modifier, as in `sr_RS@latin` or
+`zh_TW@Hant`; scripts that have a glibc modifier name are added using that name,
+and any modifier that does not denote a script is left unchanged.
+
+See also [Unicode Technical Standard #35, Likely Subtags](https://www.unicode.org/reports/tr35/#Likely_Subtags).
+
+## Examples
+
+```
+use locale_types::likely::LikelySubtags;
+use locale_types::{LanguageTag, LocaleString};
+use std::str::FromStr;
+
+let tag = LanguageTag::from_str("zh-TW").unwrap();
+assert_eq!(tag.maximize().to_string(), "zh-Hant-TW");
+assert_eq!(tag.maximize().minimize().to_string(), "zh-TW");
+
+let tag = LanguageTag::from_str("sr").unwrap();
+assert_eq!(tag.maximize().to_string(), "sr-Cyrl-RS");
+
+let locale = LocaleString::from_str("sr.UTF-8").unwrap();
+assert_eq!(locale.maximize().to_string(), "sr_RS.UTF-8@cyrillic");
+
+let locale = LocaleString::from_str("sr_RS.UTF-8@latin").unwrap();
+assert_eq!(locale.minimize().to_string(), "sr.UTF-8@latin");
+```
+*/
+
+use crate::id::LocaleIdentifier;
+use crate::string::SCRIPT_MODIFIERS;
+use crate::{LanguageTag, LocaleString};
+
+mod data;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Implemented by identifier types that support adding and removing likely subtags.
+pub trait LikelySubtags
+where
+    Self: Sized,
+{
+    /// Return a new identifier with the most likely language, script and region
+    /// added; if no likely subtags are known the identifier is returned unchanged.
+    fn maximize(&self) -> Self;
+
+    /// Return the shortest identifier that maximizes to the same value as `self`,
+    /// preferring to keep the region rather than the script where either would do.
+    fn minimize(&self) -> Self;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The version of the CLDR data used by this module.
+pub const CLDR_VERSION: &str = data::CLDR_VERSION;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - LanguageTag
+// ------------------------------------------------------------------------------------------------
+
+impl LikelySubtags for LanguageTag {
+    fn maximize(&self) -> Self {
+        if self.is_grandfathered() || self.is_private_use() {
+            return self.clone();
+        }
+        match maximize_parts(
+            &self.language_code(),
+            self.script().as_deref(),
+            self.territory().as_deref(),
+        ) {
+            Some(parts) => tag_with_parts(self, parts),
+            None => self.clone(),
+        }
+    }
+
+    fn minimize(&self) -> Self {
+        if self.is_grandfathered() || self.is_private_use() {
+            return self.clone();
+        }
+        match minimize_parts(
+            &self.language_code(),
+            self.script().as_deref(),
+            self.territory().as_deref(),
+        ) {
+            Some(parts) => tag_with_parts(self, parts),
+            None => self.clone(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - LocaleString
+// ------------------------------------------------------------------------------------------------
+
+impl LikelySubtags for LocaleString {
+    fn maximize(&self) -> Self {
+        match maximize_parts(
+            &self.language_code(),
+            self.script_code().as_deref(),
+            self.territory().as_deref(),
+        ) {
+            Some(parts) => locale_with_parts(self, parts),
+            None => self.clone(),
+        }
+    }
+
+    fn minimize(&self) -> Self {
+        match minimize_parts(
+            &self.language_code(),
+            self.script_code().as_deref(),
+            self.territory().as_deref(),
+        ) {
+            Some(parts) => locale_with_parts(self, parts),
+            None => self.clone(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+/// Language, script and region.
+type Parts = (String, Option<String>, Option<String>);
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn lookup(key: &str) -> Option<Parts> {
+    data::LIKELY_SUBTAGS
+        .binary_search_by(|(k, _)| (*k).cmp(key))
+        .ok()
+        .map(|index| {
+            let (language, script, region) = data::LIKELY_SUBTAGS[index].1;
+            (
+                language.to_string(),
+                Some(script.to_string()),
+                Some(region.to_string()),
+            )
+        })
+}
+
+/// The CLDR _add likely subtags_ algorithm, returns `None` if no data matches.
+fn maximize_parts(language: &str, script: Option<&str>, region: Option<&str>) -> Option<Parts> {
+    let mut candidates: Vec<String> = Vec::new();
+    if let (Some(script), Some(region)) = (script, region) {
+        candidates.push(format!("{}-{}-{}", language, script, region));
+    }
+    if let Some(region) = region {
+        candidates.push(format!("{}-{}", language, region));
+    }
+    if let Some(script) = script {
+        candidates.push(format!("{}-{}", language, script));
+    }
+    candidates.push(language.to_string());
+    // an unknown language may still be maximized from its script, as in TR35.
+    if let (Some(script), Some(region)) = (script, region) {
+        candidates.push(format!("{}-{}-{}", UNDETERMINED, script, region));
+    }
+    if let Some(script) = script {
+        candidates.push(format!("{}-{}", UNDETERMINED, script));
+    }
+    if language == UNDETERMINED {
+        if let Some(region) = region {
+            candidates.push(format!("{}-{}", UNDETERMINED, region));
+        }
+    }
+
+    let (likely_language, likely_script, likely_region) =
+        candidates.iter().find_map(|key| lookup(key))?;
+    Some((
+        if language == UNDETERMINED {
+            likely_language
+        } else {
+            language.to_string()
+        },
+        script.map(str::to_string).or(likely_script),
+        region.map(str::to_string).or(likely_region),
+    ))
+}
+
+/// The CLDR _remove likely subtags_ algorithm, favoring the region; returns `None`
+/// if the identifier cannot be maximized.
+fn minimize_parts(language: &str, script: Option<&str>, region: Option<&str>) -> Option<Parts> {
+    let maximized = maximize_parts(language, script, region)?;
+    let (max_language, max_script, max_region) = &maximized;
+    let trials = [
+        (None, None),
+        (None, max_region.as_deref()),
+        (max_script.as_deref(), None),
+    ];
+    for (script, region) in trials.iter() {
+        if maximize_parts(max_language, *script, *region).as_ref() == Some(&maximized) {
+            return Some((
+                max_language.clone(),
+                script.map(str::to_string),
+                region.map(str::to_string),
+            ));
+        }
+    }
+    Some(maximized)
+}
+
+fn tag_with_parts(tag: &LanguageTag, (language, script, region): Parts) -> LanguageTag {
+    let mut result = LanguageTag::new(language).unwrap();
+    if !tag.extended_languages().is_empty() {
+        result = result
+            .with_extended_languages(tag.extended_languages())
+            .unwrap();
+    }
+    if let Some(script) = script {
+        result = result.with_script(script).unwrap();
+    }
+    if let Some(region) = region {
+        result = result.with_territory(region).unwrap();
+    }
+    if !tag.variants().is_empty() {
+        result = result.with_variants(tag.variants()).unwrap();
+    }
+    for extension in tag.extensions() {
+        result = result.with_extension(extension).unwrap();
+    }
+    if !tag.private_use().is_empty() {
+        result = result.with_private_use(tag.private_use()).unwrap();
+    }
+    result
+}
+
+fn locale_with_parts(locale: &LocaleString, (language, script, region): Parts) -> LocaleString {
+    let mut result = LocaleString::new(language).unwrap();
+    if let Some(region) = region {
+        result = result.with_territory(region).unwrap();
+    }
+    if let Some(code_set) = locale.code_set() {
+        result = result.with_code_set(code_set).unwrap();
+    }
+    match (locale.script_code(), script) {
+        (Some(_), Some(_)) => locale.modifier(),
+        (Some(_), None) => None,
+        (None, Some(script)) if locale.modifier().is_none() => Some(
+            SCRIPT_MODIFIERS
+                .iter()
+                .find(|(_, code)| *code == script)
+                .map(|(name, _)| name.to_string())
+                .unwrap_or(script),
+        ),
+        (None, _) => locale.modifier(),
+    }
+    .map(|modifier| result.with_modifier(modifier).unwrap())
+    .unwrap_or(result)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const UNDETERMINED: &str = "und";
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{data, LikelySubtags};
+    use crate::{LanguageTag, LocaleString};
+
+    fn maximize(s: &str) -> String {
+        LanguageTag::from_str(s).unwrap().maximize().to_string()
+    }
+
+    fn minimize(s: &str) -> String {
+        LanguageTag::from_str(s).unwrap().minimize().to_string()
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_table_is_sorted() {
+        assert!(data::LIKELY_SUBTAGS
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_tag_maximize() {
+        assert_eq!(maximize("en"), "en-Latn-US");
+        assert_eq!(maximize("zh"), "zh-Hans-CN");
+        assert_eq!(maximize("zh-TW"), "zh-Hant-TW");
+        assert_eq!(maximize("zh-Hant"), "zh-Hant-TW");
+        assert_eq!(maximize("sr"), "sr-Cyrl-RS");
+        assert_eq!(maximize("und"), "en-Latn-US");
+        assert_eq!(maximize("und-419"), "es-Latn-419");
+        assert_eq!(maximize("und-Cyrl"), "ru-Cyrl-RU");
+        assert_eq!(maximize("en-GB"), "en-Latn-GB");
+        assert_eq!(
+            maximize("de-CH-1996-x-private"),
+            "de-Latn-CH-1996-x-private"
+        );
+        assert_eq!(maximize("sr-Latn-ME"), "sr-Latn-ME");
+        assert_eq!(maximize("i-klingon"), "i-klingon");
+    }
+
+    #[test]
+    fn test_tag_maximize_unknown_language() {
+        assert_eq!(maximize("qaa-Cyrl"), "qaa-Cyrl-RU");
+        assert_eq!(maximize("qaa-Hant"), "qaa-Hant-TW");
+        assert_eq!(maximize("qaa-Arab-EG"), "qaa-Arab-EG");
+        assert_eq!(maximize("qaa-Latn-419"), "qaa-Latn-419");
+        assert_eq!(maximize("qaa"), "qaa");
+        assert_eq!(maximize("qaa-DE"), "qaa-DE");
+        assert_eq!(minimize("qaa-Cyrl-RU"), "qaa-Cyrl");
+    }
+
+    #[test]
+    fn test_tag_minimize() {
+        assert_eq!(minimize("en-Latn-US"), "en");
+        assert_eq!(minimize("zh-Hant-TW"), "zh-TW");
+        assert_eq!(minimize("zh-Hans-CN"), "zh");
+        assert_eq!(minimize("sr-Cyrl-RS"), "sr");
+        assert_eq!(minimize("sr-Latn-RS"), "sr-Latn");
+        assert_eq!(minimize("en-Latn-GB"), "en-GB");
+        assert_eq!(minimize("und-419"), "es-419");
+    }
+
+    #[test]
+    fn test_locale_string() {
+        let maximize = |s: &str| LocaleString::from_str(s).unwrap().maximize().to_string();
+        let minimize = |s: &str| LocaleString::from_str(s).unwrap().minimize().to_string();
+        assert_eq!(maximize("zh_TW"), "zh_TW@Hant");
+        assert_eq!(maximize("en.UTF-8"), "en_US.UTF-8@latin");
+        assert_eq!(maximize("de_DE@euro"), "de_DE@euro");
+        assert_eq!(maximize("sr@latin"), "sr_RS@latin");
+        assert_eq!(minimize("zh_TW@Hant"), "zh_TW");
+        assert_eq!(minimize("en_US.UTF-8@latin"), "en.UTF-8");
+        assert_eq!(minimize("de_DE.ISO-8859-15@euro"), "de.ISO-8859-15@euro");
+        assert_eq!(minimize("sr_RS@cyrillic"), "sr");
+    }
+}
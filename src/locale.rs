@@ -8,6 +8,8 @@ by the POSIX C API. These are:
    a rather neutral locale which has the same settings across all systems and
    compilers, and therefore the exact results of a program using this locale
    are predictable. This is the locale used by default on all C programs.
+   Many systems also support the minimal locale with a code set, and possibly a
+   modifier, such as `C.UTF-8` which is the default in most container images.
 2. A path, starting with the '/' character and which resolves to a directory
    containing the POSIX definition of a locale.
 3. A locale string, represented in this crate as a `LocaleString` structure
@@ -23,10 +25,14 @@ use locale_types::{Locale,LocaleIdentifier};
 use std::str::FromStr;
 
 match Locale::from_str("C") {
-    Ok(Locale::POSIX) => (),
+    Ok(Locale::POSIX { code_set: None, modifier: None }) => (),
     _ => panic!("could not parse first locale string")
 }
 
+let locale = Locale::from_str("C.utf8").unwrap();
+assert!(locale.is_posix());
+assert_eq!(locale.to_string(), "C.UTF-8");
+
 let locale = Locale::from_str("en_US.UTF-8@Latn");
 if let Ok(Locale::String(locale_str)) = locale {
     assert_eq!(locale_str.language_code(), "en".to_string());
@@ -49,7 +55,7 @@ use std::env;
 
 if let Ok(lc_str) = env::var("LC_ALL") {
     match Locale::from_str(&lc_str) {
-        Ok(Locale::POSIX) =>
+        Ok(Locale::POSIX { .. }) =>
             println!("POSIX minimal locale"),
         Ok(Locale::Path(p)) =>
             println!("Path locale"),
@@ -67,7 +73,8 @@ use std::fmt::Display;
 use std::path::PathBuf;
 use std::str::FromStr;
 
-use crate::string::{LocaleString, ParseError};
+use crate::modifier::Modifier;
+use crate::string::{is_valid_code_set, LocaleString, ParseError, ParseErrorKind};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -75,11 +82,18 @@ use crate::string::{LocaleString, ParseError};
 
 /// This enumeration represents the three types of Locale specifiers
 /// commonly used by operating systems.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Locale {
-    /// The minimal locale specified by POSIX. Can be spoecified with
-    /// the string "POSIX" or simply "C".
-    POSIX,
+    /// The minimal locale specified by POSIX. Can be specified with
+    /// the string "POSIX" or simply "C", optionally followed by a code
+    /// set and/or modifier, as in "C.UTF-8"; it is always formatted
+    /// using the "C" spelling, as glibc does.
+    POSIX {
+        /// The code set, if any, used with the minimal locale.
+        code_set: Option<String>,
+        /// The modifier, if any, used with the minimal locale.
+        modifier: Option<Modifier>,
+    },
     /// A path to a locale specification, this library does not vslidste
     /// whether the path exists, simply that it is a valid `PathBuf`..
     Path(PathBuf),
@@ -91,21 +105,46 @@ pub enum Locale {
 // Implementations - Locale
 // ------------------------------------------------------------------------------------------------
 
-const L_C: &'static str = "C";
-const L_POSIX: &'static str = "POSIX";
-const L_PATH_SEP: &'static str = "/";
+const L_C: &str = "C";
+const L_POSIX: &str = "POSIX";
+const L_PATH_SEP: &str = "/";
+const L_UTF_8: &str = "UTF-8";
+
+const SEP_CODE_SET: char = '.';
+const SEP_MODIFIER: char = '@';
+
+impl Locale {
+    /// Return the plain minimal locale, with no code set or modifier.
+    pub fn posix() -> Self {
+        Locale::POSIX {
+            code_set: None,
+            modifier: None,
+        }
+    }
+
+    /// Returns `true` if this is the minimal locale, with or without a code
+    /// set or modifier.
+    pub fn is_posix(&self) -> bool {
+        matches!(self, Locale::POSIX { .. })
+    }
+}
 
 impl Display for Locale {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Locale::POSIX => L_POSIX.to_string(),
-                Locale::Path(s) => s.to_str().unwrap().to_string(),
-                Locale::String(s) => s.to_string(),
+        match self {
+            Locale::POSIX { code_set, modifier } => {
+                write!(f, "{}", L_C)?;
+                if let Some(code_set) = code_set {
+                    write!(f, "{}{}", SEP_CODE_SET, code_set)?;
+                }
+                if let Some(modifier) = modifier {
+                    write!(f, "{}{}", SEP_MODIFIER, modifier)?;
+                }
+                Ok(())
             }
-        )
+            Locale::Path(s) => write!(f, "{}", s.display()),
+            Locale::String(s) => write!(f, "{}", s),
+        }
     }
 }
 
@@ -113,40 +152,104 @@ impl FromStr for Locale {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() == 0 {
-            return Err(ParseError::EmptyString);
+        if s.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::EmptyString, s, 0..0));
         }
-        match s {
-            L_C => Ok(Locale::POSIX),
-            L_POSIX => Ok(Locale::POSIX),
-            _ => {
-                if s.starts_with(L_PATH_SEP) {
-                    match PathBuf::from_str(s) {
-                        Ok(p) => Ok(Locale::Path(p)),
-                        Err(_) => Err(ParseError::InvalidPath),
-                    }
-                } else {
-                    Ok(Locale::String(LocaleString::from_str(s)?))
-                }
+        if let Some(locale) = parse_posix(s)? {
+            Ok(locale)
+        } else if s.starts_with(L_PATH_SEP) {
+            match PathBuf::from_str(s) {
+                Ok(p) => Ok(Locale::Path(p)),
+                Err(_) => Err(ParseError::new(ParseErrorKind::InvalidPath, s, 0..s.len())),
             }
+        } else {
+            Ok(Locale::String(LocaleString::from_str(s)?))
         }
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Parse the minimal locale in any of its common spellings, `C`, `POSIX`, `C.UTF-8`,
+/// `C.utf8`, `POSIX.UTF-8` and so on; returns `None` if `s` is not the minimal locale.
+fn parse_posix(s: &str) -> Result<Option<Locale>, ParseError> {
+    let (rest, modifier) = match s.find(SEP_MODIFIER) {
+        Some(index) => (&s[..index], Some((index + 1, &s[index + 1..]))),
+        None => (s, None),
+    };
+    let (name, code_set) = match rest.find(SEP_CODE_SET) {
+        Some(index) => (&rest[..index], Some((index + 1, &rest[index + 1..]))),
+        None => (rest, None),
+    };
+    if name != L_C && name != L_POSIX {
+        return Ok(None);
+    }
+
+    let code_set = match code_set {
+        None => None,
+        Some((_, code_set)) if is_utf_8(code_set) => Some(L_UTF_8.to_string()),
+        Some((_, code_set)) if is_valid_code_set(code_set) => Some(code_set.to_string()),
+        Some((offset, code_set)) => {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidCodeSet,
+                s,
+                offset..offset + code_set.len(),
+            ))
+        }
+    };
+    let modifier = match modifier {
+        None => None,
+        Some((offset, modifier)) => match Modifier::from_str(modifier) {
+            Ok(modifier) => Some(modifier),
+            Err(e) if e.kind() == ParseErrorKind::EmptyString => {
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidModifier,
+                    s,
+                    offset..offset + modifier.len(),
+                ))
+            }
+            Err(e) => return Err(e.within(s, offset)),
+        },
+    };
+    Ok(Some(Locale::POSIX { code_set, modifier }))
+}
+
+/// The common spellings of UTF-8; `UTF-8`, `utf-8`, `UTF8`, `utf8` and so on.
+fn is_utf_8(code_set: &str) -> bool {
+    code_set.eq_ignore_ascii_case("utf-8") || code_set.eq_ignore_ascii_case("utf8")
+}
+
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
 mod tests {
-    use crate::{Locale, LocaleIdentifier, LocaleString};
+    use crate::string::{ParseError, ParseErrorKind};
+    use crate::{Locale, LocaleIdentifier, LocaleString, Modifier};
     use std::path::PathBuf;
     use std::str::FromStr;
 
     // --------------------------------------------------------------------------------------------
     #[test]
     fn test_posix_to_string() {
-        assert_eq!(Locale::POSIX.to_string(), "POSIX");
+        assert_eq!(Locale::posix().to_string(), "C");
+    }
+
+    #[test]
+    fn test_posix_code_set_to_string() {
+        let locale = Locale::POSIX {
+            code_set: Some("UTF-8".to_string()),
+            modifier: None,
+        };
+        assert_eq!(locale.to_string(), "C.UTF-8");
+        let locale = Locale::POSIX {
+            code_set: None,
+            modifier: Some(Modifier::from_str("euro").unwrap()),
+        };
+        assert_eq!(locale.to_string(), "C@euro");
     }
 
     #[test]
@@ -169,14 +272,55 @@ mod tests {
     // --------------------------------------------------------------------------------------------
     #[test]
     fn test_posix_from_string() {
-        match Locale::from_str("POSIX") {
-            Ok(Locale::POSIX) => (),
-            _ => panic!("expecting Locale::POSIX"),
-        }
-        match Locale::from_str("C") {
-            Ok(Locale::POSIX) => (),
-            _ => panic!("expecting Locale::POSIX (C)"),
+        assert_eq!(Locale::from_str("POSIX"), Ok(Locale::posix()));
+        assert_eq!(Locale::from_str("C"), Ok(Locale::posix()));
+    }
+
+    #[test]
+    fn test_posix_code_set_from_string() {
+        for s in &["C.UTF-8", "C.utf8", "C.UTF8", "C.utf-8", "POSIX.UTF-8"] {
+            match Locale::from_str(s) {
+                Ok(locale) => {
+                    assert_eq!(
+                        locale,
+                        Locale::POSIX {
+                            code_set: Some("UTF-8".to_string()),
+                            modifier: None,
+                        }
+                    );
+                    assert_eq!(locale.to_string(), "C.UTF-8");
+                }
+                _ => panic!("expecting Locale::POSIX"),
+            }
         }
+        let locale = Locale::from_str("C.ISO-8859-1@euro").unwrap();
+        assert!(locale.is_posix());
+        assert_eq!(locale.to_string(), "C.ISO-8859-1@euro");
+    }
+
+    #[test]
+    fn test_posix_bad_from_string() {
+        assert_eq!(
+            Locale::from_str("C. utf8"),
+            Err(ParseError::new(
+                ParseErrorKind::InvalidCodeSet,
+                "C. utf8",
+                2..7
+            ))
+        );
+        assert_eq!(
+            Locale::from_str("C.UTF-8@"),
+            Err(ParseError::new(
+                ParseErrorKind::InvalidModifier,
+                "C.UTF-8@",
+                8..8
+            ))
+        );
+        let posix = Locale::from_str("C.UTF-8@euro;b!d").unwrap_err();
+        let string = LocaleString::from_str("en.UTF-8@euro;b!d").unwrap_err();
+        assert_eq!(posix.kind(), string.kind());
+        assert_eq!(posix.span(), string.span().start - 1..string.span().end - 1);
+        assert_eq!(posix.span(), 13..16);
     }
 
     #[test]
@@ -0,0 +1,655 @@
+/*!
+Provides a best-fit locale matcher based on the CLDR language matching data,
+enabled by the `language-matching` feature.
+
+Where the RFC 4647 schemes in the [`negotiate`](../negotiate/index.html) module
+only match identifiers that share a prefix, the
+[`LanguageMatcher`](struct.LanguageMatcher.html) scores the _distance_ between a
+desired and a supported identifier, so that an `en_AU` user is offered `en_GB`
+rather than `en_US`, and a `pt_PT` user is offered `pt_BR` rather than nothing.
+
+Both identifiers are first maximized using the CLDR likely subtags, and the
+distance is then the sum of:
+
+* a language distance; 0 for the same language, the distance of the first matching
+  language rule, such as the one relating `no` and `nb`, or otherwise 80,
+* a script distance; 0 for the same script, the distance of the first matching
+  script rule, such as the one relating `sr_Latn` and `sr_Cyrl`, or otherwise 50,
+* a region distance; 0 for the same region, the distance of the first matching
+  region rule, or otherwise 4. Region rules use the CLDR _match variables_, so that
+  for example any two regions within `$americas` are closer for Spanish than a
+  region within and one outside it, and English outside `$enUS` is closest to
+  `en_GB`. A macro-region, such as `419`, stands for all the regions it contains,
+  and the largest distance between any of those regions is used.
+
+A match is only returned if its distance is below the matcher's threshold, which
+by default is the distance between two scripts, so that a user is not offered a
+locale they may be unable to read. Where two supported identifiers are the same
+distance from the desired one, a CLDR _paradigm locale_, one of `en`, `en_GB`,
+`es`, `es_419`, `pt_BR` and `pt_PT`, is preferred. The CLDR distances are on a
+scale of 0 to 100, and the confidence of a match is `1.0` for an exact match,
+falling towards `0.0` as the distance approaches 100.
+
+The rules, match variables and paradigm locales are an embedded snapshot of the
+CLDR `languageMatching` data, see `CLDR_VERSION`.
+
+See also [Unicode Technical Standard #35, Language Matching](https://www.unicode.org/reports/tr35/#LanguageMatching).
+
+## Examples
+
+```
+use locale_types::matcher::LanguageMatcher;
+use locale_types::LocaleString;
+use std::str::FromStr;
+
+let supported: Vec<LocaleString> = ["en_US", "en_GB", "pt_BR", "de_DE"]
+    .iter()
+    .map(|s| LocaleString::from_str(s).unwrap())
+    .collect();
+let matcher = LanguageMatcher::new();
+
+let desired = [LocaleString::from_str("en_AU").unwrap()];
+let best = matcher.best_match(&desired, &supported).unwrap();
+assert_eq!(best.value().to_string(), "en_GB");
+
+let desired = [LocaleString::from_str("pt_PT").unwrap()];
+let best = matcher.best_match(&desired, &supported).unwrap();
+assert_eq!(best.value().to_string(), "pt_BR");
+assert!(best.confidence() > 0.9);
+
+let desired = [LocaleString::from_str("ja_JP").unwrap()];
+assert!(matcher.best_match(&desired, &supported).is_none());
+```
+*/
+
+use std::str::FromStr;
+
+use crate::id::LocaleIdentifier;
+use crate::likely::LikelySubtags;
+use crate::negotiate::Negotiable;
+use crate::LanguageTag;
+
+mod data;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A best-fit matcher, configured with a threshold and a demotion applied to each
+/// successive desired identifier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageMatcher {
+    threshold: u8,
+    demotion: u8,
+}
+
+/// The result of a successful match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match<T> {
+    value: T,
+    desired_index: usize,
+    supported_index: usize,
+    distance: u8,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The version of the CLDR data used by this module.
+pub const CLDR_VERSION: &str = data::CLDR_VERSION;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - LanguageMatcher
+// ------------------------------------------------------------------------------------------------
+
+impl Default for LanguageMatcher {
+    fn default() -> Self {
+        let en_us = parts("en", "Latn", "US");
+        let en_gb = parts("en", "Latn", "GB");
+        LanguageMatcher {
+            threshold: data::DEFAULT_SCRIPT_DISTANCE,
+            demotion: distance(&en_us, &en_gb),
+        }
+    }
+}
+
+impl LanguageMatcher {
+    /// Construct a matcher with the default threshold, the distance between two
+    /// scripts, and the default demotion, the distance between `en_US` and `en_GB`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Return a new matcher based on `self` that only returns matches with a
+    /// distance below `threshold`.
+    pub fn with_threshold(&self, threshold: u8) -> Self {
+        LanguageMatcher {
+            threshold,
+            ..self.clone()
+        }
+    }
+
+    /// Return a new matcher based on `self` that adds `demotion` to the distance of
+    /// each desired identifier for every identifier preceding it in the desired list.
+    pub fn with_demotion(&self, demotion: u8) -> Self {
+        LanguageMatcher {
+            demotion,
+            ..self.clone()
+        }
+    }
+
+    /// Return the threshold of this matcher.
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Return the demotion of this matcher.
+    pub fn demotion(&self) -> u8 {
+        self.demotion
+    }
+
+    /// Return the distance between `desired` and `supported`, from 0 for identical
+    /// identifiers to 100 for identifiers that cannot be compared, such as the
+    /// POSIX minimal locale.
+    pub fn distance<D, S>(&self, desired: &D, supported: &S) -> u8
+    where
+        D: Negotiable,
+        S: Negotiable,
+    {
+        match (maximized(desired), maximized(supported)) {
+            (Some(desired), Some(supported)) => distance(&desired, &supported),
+            _ => MAX_DISTANCE,
+        }
+    }
+
+    /// Return the supported identifier that best matches the list of desired
+    /// identifiers, most preferred first, or `None` if no supported identifier is
+    /// within the threshold. Ties are resolved in favor of the earliest desired
+    /// identifier, then a paradigm locale, and then the earliest supported identifier.
+    pub fn best_match<D, S>(&self, desired: &[D], supported: &[S]) -> Option<Match<S>>
+    where
+        D: Negotiable,
+        S: Negotiable,
+    {
+        let supported_parts: Vec<Option<Parts>> = supported.iter().map(maximized).collect();
+        let threshold = u32::from(self.threshold);
+        let mut best: Option<(u32, usize, bool, usize)> = None;
+        for (desired_index, desired) in desired.iter().enumerate() {
+            let demotion = (desired_index as u32).saturating_mul(u32::from(self.demotion));
+            if demotion >= threshold {
+                // no later desired identifier can be within the threshold.
+                break;
+            }
+            let desired = match maximized(desired) {
+                Some(desired) => desired,
+                None => continue,
+            };
+            for (supported_index, supported) in supported_parts.iter().enumerate() {
+                if let Some(supported) = supported {
+                    let score = u32::from(distance(&desired, supported)).saturating_add(demotion);
+                    // the key orders candidates so that the smallest is the best match.
+                    let key = (
+                        score,
+                        desired_index,
+                        !is_paradigm(supported),
+                        supported_index,
+                    );
+                    let better = match best {
+                        Some(best) => key < best,
+                        None => true,
+                    };
+                    if score < threshold && better {
+                        best = Some(key);
+                    }
+                }
+            }
+        }
+        best.map(|(score, desired_index, _, supported_index)| Match {
+            value: supported[supported_index].clone(),
+            desired_index,
+            supported_index,
+            distance: score.min(u32::from(MAX_DISTANCE)) as u8,
+        })
+    }
+
+    /// As `best_match`, but return a match for `default` with no confidence if no
+    /// supported identifier is within the threshold.
+    pub fn best_match_or<D, S>(&self, desired: &[D], supported: &[S], default: S) -> Match<S>
+    where
+        D: Negotiable,
+        S: Negotiable,
+    {
+        self.best_match(desired, supported).unwrap_or(Match {
+            value: default,
+            desired_index: 0,
+            supported_index: supported.len(),
+            distance: MAX_DISTANCE,
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - Match
+// ------------------------------------------------------------------------------------------------
+
+impl<T> Match<T> {
+    /// Return a reference to the matched supported value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Return the matched supported value.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Return the index of the desired identifier that was matched.
+    pub fn desired_index(&self) -> usize {
+        self.desired_index
+    }
+
+    /// Return the index of the matched value in the supported list; for a default
+    /// match this is the length of the supported list.
+    pub fn supported_index(&self) -> usize {
+        self.supported_index
+    }
+
+    /// Return the distance of this match, including any demotion.
+    pub fn distance(&self) -> u8 {
+        self.distance
+    }
+
+    /// Return the confidence of this match, from `1.0` for an exact match to `0.0`
+    /// for a distance of 100, the largest distance on the CLDR scale.
+    pub fn confidence(&self) -> f64 {
+        1.0 - f64::from(self.distance.min(MAX_DISTANCE)) / f64::from(MAX_DISTANCE)
+    }
+
+    /// Returns `true` if the desired and supported identifiers are equivalent.
+    pub fn is_exact(&self) -> bool {
+        self.distance == 0
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+/// Maximized language, script and region.
+type Parts = (String, String, String);
+
+/// Language and script of a CLDR script match rule.
+type ScriptPattern = (&'static str, &'static str);
+
+/// Language, script and region patterns of a CLDR region match rule.
+type RegionPattern = (&'static str, &'static str, &'static str);
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn maximized<T: Negotiable>(value: &T) -> Option<Parts> {
+    let tag = LanguageTag::from_str(&value.subtags()?.join("-")).ok()?;
+    if tag.is_grandfathered() || tag.is_private_use() {
+        return None;
+    }
+    let tag = tag.maximize();
+    Some((
+        tag.language_code(),
+        tag.script().unwrap_or_default(),
+        tag.territory().unwrap_or_default(),
+    ))
+}
+
+fn parts(language: &str, script: &str, region: &str) -> Parts {
+    (language.to_string(), script.to_string(), region.to_string())
+}
+
+fn distance(desired: &Parts, supported: &Parts) -> u8 {
+    let language_distance = if desired.0 == supported.0 {
+        0
+    } else {
+        data::LANGUAGE_MATCHES
+            .iter()
+            .find(|(d, s, _, oneway)| {
+                (*d == desired.0 && *s == supported.0)
+                    || (!oneway && *d == supported.0 && *s == desired.0)
+            })
+            .map_or(data::DEFAULT_LANGUAGE_DISTANCE, |(_, _, distance, _)| {
+                *distance
+            })
+    };
+    let script_distance = if desired.1 == supported.1 {
+        0
+    } else {
+        let matches = |(language, script): &ScriptPattern, parts: &Parts| {
+            *language == parts.0 && *script == parts.1
+        };
+        data::SCRIPT_MATCHES
+            .iter()
+            .find(|(d, s, _, oneway)| {
+                (matches(d, desired) && matches(s, supported))
+                    || (!oneway && matches(d, supported) && matches(s, desired))
+            })
+            .map_or(data::DEFAULT_SCRIPT_DISTANCE, |(_, _, distance, _)| {
+                *distance
+            })
+    };
+    let region_distance = if desired.2 == supported.2 {
+        0
+    } else {
+        region_distance(desired, supported)
+    };
+    (language_distance + script_distance + region_distance).min(MAX_DISTANCE)
+}
+
+/// The distance between two different regions; a macro-region stands for each of
+/// the regions it contains, and the largest distance between them is used.
+fn region_distance(desired: &Parts, supported: &Parts) -> u8 {
+    let matches = |(language, script, _): &RegionPattern, parts: &Parts| {
+        *language == parts.0 && (*script == WILDCARD || *script == parts.1)
+    };
+    // the rules that apply to these languages and scripts, as desired and supported
+    // region patterns.
+    let mut rules: Vec<(&str, &str, u8)> = Vec::new();
+    for (d, s, distance, oneway) in data::REGION_MATCHES.iter() {
+        if matches(d, desired) && matches(s, supported) {
+            rules.push((d.2, s.2, *distance));
+        }
+        if !oneway && matches(d, supported) && matches(s, desired) {
+            rules.push((s.2, d.2, *distance));
+        }
+    }
+    if rules.is_empty() {
+        return data::DEFAULT_REGION_DISTANCE;
+    }
+
+    let patterns: Vec<&str> = rules.iter().flat_map(|(d, s, _)| vec![*d, *s]).collect();
+    let desired_regions = distinct_regions(&desired.2, &patterns);
+    let supported_regions = distinct_regions(&supported.2, &patterns);
+    desired_regions
+        .iter()
+        .flat_map(|d| supported_regions.iter().map(move |s| (*d, *s)))
+        .map(|(desired, supported)| {
+            rules
+                .iter()
+                .find(|(d, s, _)| region_matches(d, desired) && region_matches(s, supported))
+                .map_or(data::DEFAULT_REGION_DISTANCE, |(_, _, distance)| *distance)
+        })
+        .max()
+        .unwrap_or(data::DEFAULT_REGION_DISTANCE)
+}
+
+/// One region for each distinct way the regions within `region` match `patterns`.
+fn distinct_regions<'a>(region: &'a str, patterns: &[&str]) -> Vec<&'a str> {
+    let mut found: Vec<(Vec<bool>, &str)> = Vec::new();
+    for region in contained_regions(region) {
+        let matched: Vec<bool> = patterns.iter().map(|p| region_matches(p, region)).collect();
+        if !found.iter().any(|(m, _)| *m == matched) {
+            found.push((matched, region));
+        }
+    }
+    found.into_iter().map(|(_, region)| region).collect()
+}
+
+/// The regions within `region`, or `region` itself if it is not a macro-region.
+fn contained_regions(region: &str) -> Vec<&str> {
+    match children(region) {
+        Some(children) => children
+            .iter()
+            .flat_map(|child| contained_regions(child))
+            .collect(),
+        None => vec![region],
+    }
+}
+
+fn children(region: &str) -> Option<&'static [&'static str]> {
+    data::REGION_CONTAINMENT
+        .binary_search_by(|(r, _)| (*r).cmp(region))
+        .ok()
+        .map(|index| data::REGION_CONTAINMENT[index].1)
+}
+
+fn is_within(region: &str, container: &str) -> bool {
+    region == container
+        || children(container)
+            .unwrap_or_default()
+            .iter()
+            .any(|child| is_within(region, child))
+}
+
+fn region_matches(pattern: &str, region: &str) -> bool {
+    if pattern == WILDCARD {
+        true
+    } else if let Some(variable) = pattern.strip_prefix(VARIABLE_NEGATED) {
+        !in_variable(variable, region)
+    } else if let Some(variable) = pattern.strip_prefix(VARIABLE) {
+        in_variable(variable, region)
+    } else {
+        pattern == region
+    }
+}
+
+fn in_variable(variable: &str, region: &str) -> bool {
+    data::MATCH_VARIABLES
+        .iter()
+        .filter(|(name, _)| *name == variable)
+        .flat_map(|(_, regions)| regions.iter())
+        .any(|container| is_within(region, container))
+}
+
+fn is_paradigm(parts: &Parts) -> bool {
+    data::PARADIGM_LOCALES
+        .iter()
+        .any(|(l, s, r)| *l == parts.0 && *s == parts.1 && *r == parts.2)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const MAX_DISTANCE: u8 = 100;
+
+const WILDCARD: &str = "*";
+const VARIABLE: &str = "$";
+const VARIABLE_NEGATED: &str = "$!";
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{data, LanguageMatcher};
+    use crate::{LanguageTag, Locale, LocaleString};
+
+    fn tag(s: &str) -> LanguageTag {
+        LanguageTag::from_str(s).unwrap()
+    }
+
+    fn tags(tags: &[&str]) -> Vec<LanguageTag> {
+        tags.iter().map(|s| tag(s)).collect()
+    }
+
+    fn best(desired: &[&str], supported: &[&str]) -> Option<String> {
+        LanguageMatcher::new()
+            .best_match(&tags(desired), &tags(supported))
+            .map(|m| m.value().to_string())
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_containment_is_sorted() {
+        assert!(data::REGION_CONTAINMENT
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_distance() {
+        let matcher = LanguageMatcher::new();
+        assert_eq!(matcher.distance(&tag("en"), &tag("en-Latn-US")), 0);
+        assert_eq!(matcher.distance(&tag("no"), &tag("nb")), 1);
+        assert_eq!(matcher.distance(&tag("en-AU"), &tag("en-GB")), 3);
+        assert_eq!(matcher.distance(&tag("en-GB"), &tag("en-AU")), 3);
+        assert_eq!(matcher.distance(&tag("en-AU"), &tag("en-US")), 5);
+        assert_eq!(matcher.distance(&tag("en-CA"), &tag("en-PH")), 4);
+        assert_eq!(matcher.distance(&tag("es-MX"), &tag("es-419")), 4);
+        assert_eq!(matcher.distance(&tag("es-MX"), &tag("es-ES")), 5);
+        assert_eq!(matcher.distance(&tag("ar-MA"), &tag("ar-DZ")), 4);
+        assert_eq!(matcher.distance(&tag("ar-MA"), &tag("ar-EG")), 5);
+        assert_eq!(matcher.distance(&tag("zh-HK"), &tag("zh-MO")), 4);
+        assert_eq!(matcher.distance(&tag("zh-HK"), &tag("zh-TW")), 5);
+        assert_eq!(matcher.distance(&tag("zh-TW"), &tag("zh-CN")), 54);
+        assert_eq!(matcher.distance(&tag("en"), &tag("fr")), 84);
+        assert_eq!(matcher.distance(&tag("sr-Latn"), &tag("sr")), 5);
+        assert_eq!(matcher.distance(&tag("sr"), &tag("sr-Latn")), 5);
+        assert_eq!(matcher.distance(&tag("ja-Latn"), &tag("ja")), 5);
+        assert_eq!(matcher.distance(&tag("ja"), &tag("ja-Latn")), 50);
+        assert_eq!(matcher.distance(&tag("am"), &tag("en")), 44);
+        assert_eq!(matcher.distance(&tag("en"), &tag("am")), 100);
+    }
+
+    #[test]
+    fn test_macro_regions() {
+        let matcher = LanguageMatcher::new();
+        // 419 is within $americas, 150 is not, and 001 is partly within it.
+        assert_eq!(matcher.distance(&tag("es-419"), &tag("es-AR")), 4);
+        assert_eq!(matcher.distance(&tag("es-150"), &tag("es-ES")), 4);
+        assert_eq!(matcher.distance(&tag("es-150"), &tag("es-AR")), 5);
+        assert_eq!(matcher.distance(&tag("es-001"), &tag("es-ES")), 5);
+        assert_eq!(matcher.distance(&tag("en-150"), &tag("en-GB")), 3);
+    }
+
+    #[test]
+    fn test_defaults_from_data() {
+        let matcher = LanguageMatcher::new();
+        assert_eq!(matcher.threshold(), data::DEFAULT_SCRIPT_DISTANCE);
+        assert_eq!(matcher.demotion(), 5);
+        assert_eq!(super::CLDR_VERSION, "42");
+    }
+
+    #[test]
+    fn test_best_match() {
+        assert_eq!(
+            best(&["en-AU"], &["en", "en-GB"]),
+            Some("en-GB".to_string())
+        );
+        assert_eq!(
+            best(&["pt-PT"], &["en", "pt-BR"]),
+            Some("pt-BR".to_string())
+        );
+        assert_eq!(
+            best(&["es-AR"], &["es", "es-419"]),
+            Some("es-419".to_string())
+        );
+        assert_eq!(best(&["fr", "de"], &["en", "de"]), Some("de".to_string()));
+        assert_eq!(best(&["nb"], &["no", "da"]), Some("no".to_string()));
+        assert_eq!(best(&["zh-TW"], &["zh-CN", "en"]), None);
+        assert_eq!(
+            best(&["zh-HK"], &["zh-CN", "zh-TW"]),
+            Some("zh-TW".to_string())
+        );
+        assert_eq!(best(&["ja"], &["en", "de"]), None);
+        assert_eq!(
+            best(&["en-US", "en-GB"], &["en-GB", "en-US"]),
+            Some("en-US".to_string())
+        );
+    }
+
+    #[test]
+    fn test_paradigm_preferred() {
+        assert_eq!(
+            best(&["pt-AO"], &["pt-MZ", "pt-PT"]),
+            Some("pt-PT".to_string())
+        );
+        assert_eq!(
+            best(&["es-AR"], &["es-MX", "es-419"]),
+            Some("es-419".to_string())
+        );
+        assert_eq!(
+            best(&["es-AR"], &["es-MX", "es-CO"]),
+            Some("es-MX".to_string())
+        );
+    }
+
+    #[test]
+    fn test_demotion() {
+        let matcher = LanguageMatcher::new();
+        let found = matcher
+            .best_match(&tags(&["fr", "en-AU"]), &tags(&["en-GB", "fr-CA"]))
+            .unwrap();
+        assert_eq!(found.value().to_string(), "fr-CA");
+        assert_eq!(found.desired_index(), 0);
+        assert_eq!(found.supported_index(), 1);
+        let found = matcher
+            .with_demotion(0)
+            .best_match(&tags(&["fr", "en-AU"]), &tags(&["en-GB", "fr-CA"]))
+            .unwrap();
+        assert_eq!(found.value().to_string(), "en-GB");
+    }
+
+    #[test]
+    fn test_long_desired() {
+        let mut desired = vec![tag("ja"); 70_000];
+        desired.push(tag("en"));
+        let supported = tags(&["en"]);
+        assert!(LanguageMatcher::new()
+            .with_demotion(255)
+            .best_match(&desired, &supported)
+            .is_none());
+        assert!(LanguageMatcher::new()
+            .best_match(&desired, &supported)
+            .is_none());
+        desired.insert(0, tag("en"));
+        let found = LanguageMatcher::new()
+            .with_demotion(255)
+            .best_match(&desired, &supported)
+            .unwrap();
+        assert_eq!(found.desired_index(), 0);
+    }
+
+    #[test]
+    fn test_confidence() {
+        let matcher = LanguageMatcher::new();
+        let exact = matcher
+            .best_match(&tags(&["en"]), &tags(&["en-US"]))
+            .unwrap();
+        assert!(exact.is_exact());
+        assert_eq!(exact.confidence(), 1.0);
+        let close = matcher
+            .best_match(&tags(&["pt-PT"]), &tags(&["pt-BR"]))
+            .unwrap();
+        assert_eq!(close.distance(), 5);
+        assert_eq!(close.confidence(), 0.95);
+        assert!(matcher
+            .with_threshold(5)
+            .best_match(&tags(&["pt-PT"]), &tags(&["pt-BR"]))
+            .is_none());
+    }
+
+    #[test]
+    fn test_locales() {
+        let supported: Vec<Locale> = ["C", "en_US.UTF-8", "en_GB.UTF-8"]
+            .iter()
+            .map(|s| Locale::from_str(s).unwrap())
+            .collect();
+        let desired = [LocaleString::from_str("en_NZ").unwrap()];
+        let matcher = LanguageMatcher::new();
+        assert_eq!(
+            matcher
+                .best_match(&desired, &supported)
+                .unwrap()
+                .value()
+                .to_string(),
+            "en_GB.UTF-8"
+        );
+        let desired = [LocaleString::from_str("ko_KR").unwrap()];
+        let found = matcher.best_match_or(&desired, &supported, Locale::posix());
+        assert_eq!(found.value(), &Locale::posix());
+        assert_eq!(found.confidence(), 0.0);
+    }
+}
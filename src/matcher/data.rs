@@ -0,0 +1,464 @@
+// Generated from the CLDR languageMatching and territoryContainment data, release 42;
+// do not edit by hand.
+
+use super::{RegionPattern, ScriptPattern};
+
+/// The CLDR version this data was taken from.
+pub(super) const CLDR_VERSION: &str = "42";
+
+/// The distance between different languages where no rule applies, the CLDR `*` rule.
+pub(super) const DEFAULT_LANGUAGE_DISTANCE: u8 = 80;
+
+/// The distance between different scripts where no rule applies, the CLDR `*_*` rule.
+pub(super) const DEFAULT_SCRIPT_DISTANCE: u8 = 50;
+
+/// The distance between different regions where no rule applies, the CLDR `*_*_*` rule.
+pub(super) const DEFAULT_REGION_DISTANCE: u8 = 4;
+
+/// The CLDR paradigm locales, maximized to language, script and region.
+#[rustfmt::skip]
+pub(super) static PARADIGM_LOCALES: [(&str, &str, &str); 6] = [
+    ("en", "Latn", "US"), ("en", "Latn", "GB"), ("es", "Latn", "ES"),
+    ("es", "Latn", "419"), ("pt", "Latn", "BR"), ("pt", "Latn", "PT"),
+];
+
+/// The CLDR match variables and their regions; a macro-region stands for all the
+/// regions it contains.
+#[rustfmt::skip]
+pub(super) static MATCH_VARIABLES: [(&str, &[&str]); 4] = [
+    ("americas", &["019"]),
+    ("cnsar", &["HK", "MO"]),
+    ("enUS", &["AS", "CA", "GU", "MH", "MP", "PH", "PR", "UM", "US", "VI"]),
+    ("maghreb", &["DZ", "EH", "LY", "MA", "MR", "TN"]),
+];
+
+/// Macro-regions and the regions they contain, ordered by macro-region.
+#[rustfmt::skip]
+pub(super) static REGION_CONTAINMENT: [(&str, &[&str]); 35] = [
+    ("001", &["002", "009", "019", "142", "150"]),
+    ("002", &["011", "014", "015", "017", "018"]),
+    ("003", &["013", "021", "029"]),
+    ("005", &["AR", "BO", "BR", "BV", "CL", "CO", "EC", "FK", "GF", "GS", "GY", "PE", "PY", "SR", "UY", "VE"]),
+    ("009", &["053", "054", "057", "061", "QO"]),
+    ("011", &["BF", "BJ", "CI", "CV", "GH", "GM", "GN", "GW", "LR", "ML", "MR", "NE", "NG", "SH", "SL", "SN", "TG"]),
+    ("013", &["BZ", "CR", "GT", "HN", "MX", "NI", "PA", "SV"]),
+    ("014", &["BI", "DJ", "ER", "ET", "IO", "KE", "KM", "MG", "MU", "MW", "MZ", "RE", "RW", "SC", "SO", "SS", "TF", "TZ", "UG", "YT", "ZM", "ZW"]),
+    ("015", &["DZ", "EA", "EG", "EH", "IC", "LY", "MA", "SD", "TN"]),
+    ("017", &["AO", "CD", "CF", "CG", "CM", "GA", "GQ", "ST", "TD"]),
+    ("018", &["BW", "LS", "NA", "SZ", "ZA"]),
+    ("019", &["005", "013", "021", "029"]),
+    ("021", &["BM", "CA", "GL", "PM", "US"]),
+    ("029", &["AG", "AI", "AW", "BB", "BL", "BQ", "BS", "CU", "CW", "DM", "DO", "GD", "GP", "HT", "JM", "KN", "KY", "LC", "MF", "MQ", "MS", "PR", "SX", "TC", "TT", "VC", "VG", "VI"]),
+    ("030", &["CN", "HK", "JP", "KP", "KR", "MN", "MO", "TW"]),
+    ("034", &["AF", "BD", "BT", "IN", "IR", "LK", "MV", "NP", "PK"]),
+    ("035", &["BN", "ID", "KH", "LA", "MM", "MY", "PH", "SG", "TH", "TL", "VN"]),
+    ("039", &["AD", "AL", "BA", "ES", "GI", "GR", "HR", "IT", "ME", "MK", "MT", "PT", "RS", "SI", "SM", "VA", "XK"]),
+    ("053", &["AU", "CC", "CX", "HM", "NF", "NZ"]),
+    ("054", &["FJ", "NC", "PG", "SB", "VU"]),
+    ("057", &["FM", "GU", "KI", "MH", "MP", "NR", "PW", "UM"]),
+    ("061", &["AS", "CK", "NU", "PF", "PN", "TK", "TO", "TV", "WF", "WS"]),
+    ("142", &["030", "034", "035", "143", "145"]),
+    ("143", &["KG", "KZ", "TJ", "TM", "UZ"]),
+    ("145", &["AE", "AM", "AZ", "BH", "CY", "GE", "IL", "IQ", "JO", "KW", "LB", "OM", "PS", "QA", "SA", "SY", "TR", "YE"]),
+    ("150", &["039", "151", "154", "155"]),
+    ("151", &["BG", "BY", "CZ", "HU", "MD", "PL", "RO", "RU", "SK", "UA"]),
+    ("154", &["AX", "DK", "EE", "FI", "FO", "GB", "GG", "IE", "IM", "IS", "JE", "LT", "LV", "NO", "SE", "SJ"]),
+    ("155", &["AT", "BE", "CH", "DE", "FR", "LI", "LU", "MC", "NL"]),
+    ("202", &["011", "014", "017", "018"]),
+    ("419", &["005", "013", "029"]),
+    ("EU", &["AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "ES", "FI", "FR", "GR", "HR", "HU", "IE", "IT", "LT", "LU", "LV", "MT", "NL", "PL", "PT", "RO", "SE", "SI", "SK"]),
+    ("EZ", &["AT", "BE", "CY", "DE", "EE", "ES", "FI", "FR", "GR", "IE", "IT", "LT", "LU", "LV", "MT", "NL", "PT", "SI", "SK"]),
+    ("QO", &["AC", "AQ", "CP", "DG", "TA"]),
+    ("UN", &["AD", "AE", "AF", "AG", "AL", "AM", "AO", "AR", "AT", "AU", "AZ", "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BN", "BO", "BR", "BS", "BT", "BW", "BY", "BZ", "CA", "CD", "CF", "CG", "CH", "CI", "CL", "CM", "CN", "CO", "CR", "CU", "CV", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE", "EG", "ER", "ES", "ET", "FI", "FJ", "FM", "FR", "GA", "GB", "GD", "GE", "GH", "GM", "GN", "GQ", "GR", "GT", "GW", "GY", "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IN", "IQ", "IR", "IS", "IT", "JM", "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KZ", "LA", "LB", "LC", "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MG", "MH", "MK", "ML", "MM", "MN", "MR", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA", "NE", "NG", "NI", "NL", "NO", "NP", "NR", "NZ", "OM", "PA", "PE", "PG", "PH", "PK", "PL", "PT", "PW", "PY", "QA", "RO", "RS", "RU", "RW", "SA", "SB", "SC", "SD", "SE", "SG", "SI", "SK", "SL", "SM", "SN", "SO", "SR", "SS", "ST", "SV", "SY", "SZ", "TD", "TG", "TH", "TJ", "TL", "TM", "TN", "TO", "TR", "TT", "TV", "TZ", "UA", "UG", "US", "UY", "UZ", "VC", "VE", "VN", "VU", "WS", "YE", "ZA", "ZM", "ZW"]),
+];
+
+/// Desired and supported language, distance, and whether the rule applies in only
+/// one direction.
+#[rustfmt::skip]
+pub(super) static LANGUAGE_MATCHES: [(&str, &str, u8, bool); 306] = [
+    ("aa", "ssy", 4, false),
+    ("aae", "sq", 10, true),
+    ("aao", "ar", 10, true),
+    ("aat", "sq", 10, true),
+    ("ab", "ru", 30, true),
+    ("abh", "ar", 10, true),
+    ("abv", "ar", 10, true),
+    ("ach", "en", 30, true),
+    ("acm", "ar", 10, true),
+    ("acq", "ar", 10, true),
+    ("acw", "ar", 10, true),
+    ("acx", "ar", 10, true),
+    ("acy", "ar", 10, true),
+    ("adf", "ar", 10, true),
+    ("aeb", "ar", 10, true),
+    ("aec", "ar", 10, true),
+    ("af", "nl", 20, true),
+    ("afb", "ar", 10, true),
+    ("aii", "syr", 10, true),
+    ("ajp", "ar", 10, true),
+    ("ak", "en", 30, true),
+    ("aln", "sq", 10, true),
+    ("am", "en", 30, true),
+    ("apc", "ar", 10, true),
+    ("apd", "ar", 10, true),
+    ("arq", "ar", 10, true),
+    ("ars", "ar", 10, true),
+    ("ary", "ar", 10, true),
+    ("arz", "ar", 10, true),
+    ("auz", "ar", 10, true),
+    ("avl", "ar", 10, true),
+    ("ay", "es", 20, true),
+    ("ayh", "ar", 10, true),
+    ("ayl", "ar", 10, true),
+    ("ayn", "ar", 10, true),
+    ("ayp", "ar", 10, true),
+    ("az", "ru", 30, true),
+    ("azb", "az", 10, true),
+    ("bal", "ur", 20, true),
+    ("bbz", "ar", 10, true),
+    ("be", "ru", 20, true),
+    ("bem", "en", 30, true),
+    ("bh", "hi", 30, true),
+    ("bhr", "mg", 10, true),
+    ("bjn", "ms", 10, true),
+    ("bjq", "mg", 10, true),
+    ("bmm", "mg", 10, true),
+    ("bn", "en", 30, true),
+    ("bo", "zh", 20, true),
+    ("br", "fr", 20, true),
+    ("bs", "hr", 4, false),
+    ("bs", "sh", 4, false),
+    ("btj", "ms", 10, true),
+    ("bve", "ms", 10, true),
+    ("bvu", "ms", 10, true),
+    ("bzc", "mg", 10, true),
+    ("ca", "es", 20, true),
+    ("cdo", "zh", 10, true),
+    ("ceb", "fil", 30, true),
+    ("chr", "en", 20, true),
+    ("cjy", "zh", 10, true),
+    ("ckb", "ar", 30, true),
+    ("co", "fr", 20, true),
+    ("coa", "ms", 10, true),
+    ("cpx", "zh", 10, true),
+    ("crs", "fr", 20, true),
+    ("cs", "sk", 20, false),
+    ("cy", "en", 20, true),
+    ("czh", "zh", 10, true),
+    ("czo", "zh", 10, true),
+    ("da", "nb", 8, false),
+    ("da", "no", 8, false),
+    ("dty", "ne", 10, true),
+    ("dup", "ms", 10, true),
+    ("ee", "en", 30, true),
+    ("enb", "kln", 10, true),
+    ("eo", "en", 30, true),
+    ("eu", "es", 20, true),
+    ("eyo", "kln", 10, true),
+    ("ffm", "ff", 10, true),
+    ("fo", "da", 20, true),
+    ("fub", "ff", 10, true),
+    ("fue", "ff", 10, true),
+    ("fuf", "ff", 10, true),
+    ("fuh", "ff", 10, true),
+    ("fui", "ff", 10, true),
+    ("fuq", "ff", 10, true),
+    ("fuv", "ff", 10, true),
+    ("fy", "nl", 20, true),
+    ("ga", "en", 20, true),
+    ("gaa", "en", 30, true),
+    ("gan", "zh", 10, true),
+    ("gax", "om", 10, true),
+    ("gd", "en", 20, true),
+    ("gkp", "kpe", 10, true),
+    ("gl", "es", 20, true),
+    ("gn", "es", 20, true),
+    ("gnw", "gn", 10, true),
+    ("gom", "kok", 10, true),
+    ("gsw", "de", 4, true),
+    ("gu", "hi", 30, true),
+    ("gui", "gn", 10, true),
+    ("gun", "gn", 10, true),
+    ("ha", "en", 30, true),
+    ("hae", "om", 10, true),
+    ("hak", "zh", 10, true),
+    ("haw", "en", 20, true),
+    ("hji", "ms", 10, true),
+    ("hr", "sh", 4, false),
+    ("hsn", "zh", 10, true),
+    ("ht", "fr", 20, true),
+    ("hy", "ru", 30, true),
+    ("ia", "en", 30, true),
+    ("id", "ms", 10, true),
+    ("ida", "luy", 10, true),
+    ("ig", "en", 30, true),
+    ("ikt", "iu", 10, true),
+    ("is", "en", 20, true),
+    ("jak", "ms", 10, true),
+    ("jax", "ms", 10, true),
+    ("jv", "id", 20, true),
+    ("ka", "en", 30, true),
+    ("kg", "fr", 30, true),
+    ("kk", "ru", 30, true),
+    ("km", "en", 30, true),
+    ("kn", "en", 30, true),
+    ("kri", "en", 30, true),
+    ("ku", "tr", 30, true),
+    ("kvb", "ms", 10, true),
+    ("kvr", "ms", 10, true),
+    ("kxd", "ms", 10, true),
+    ("ky", "ru", 30, true),
+    ("la", "it", 20, true),
+    ("lb", "de", 4, true),
+    ("lce", "ms", 10, true),
+    ("lcf", "ms", 10, true),
+    ("lg", "en", 30, true),
+    ("liw", "ms", 10, true),
+    ("lkb", "luy", 10, true),
+    ("lko", "luy", 10, true),
+    ("lks", "luy", 10, true),
+    ("ln", "fr", 30, true),
+    ("lo", "en", 30, true),
+    ("loz", "en", 30, true),
+    ("lri", "luy", 10, true),
+    ("lrm", "luy", 10, true),
+    ("lsm", "luy", 10, true),
+    ("ltg", "lv", 10, true),
+    ("lto", "luy", 10, true),
+    ("lts", "luy", 10, true),
+    ("lua", "fr", 30, true),
+    ("lwg", "luy", 10, true),
+    ("lzh", "zh", 10, true),
+    ("mai", "hi", 20, true),
+    ("max", "ms", 10, true),
+    ("meo", "ms", 10, true),
+    ("mfa", "ms", 10, true),
+    ("mfb", "ms", 10, true),
+    ("mfe", "en", 30, true),
+    ("mg", "fr", 30, true),
+    ("mi", "en", 20, true),
+    ("min", "ms", 10, true),
+    ("ml", "en", 30, true),
+    ("mn", "ru", 30, true),
+    ("mnp", "zh", 10, true),
+    ("mqg", "ms", 10, true),
+    ("mr", "hi", 30, true),
+    ("ms", "id", 30, true),
+    ("msh", "mg", 10, true),
+    ("msi", "ms", 10, true),
+    ("mt", "en", 30, true),
+    ("mui", "ms", 10, true),
+    ("mvf", "mn", 10, true),
+    ("my", "en", 30, true),
+    ("nan", "zh", 10, true),
+    ("nb", "nn", 20, false),
+    ("nb", "no", 1, false),
+    ("ne", "en", 30, true),
+    ("nhd", "gn", 10, true),
+    ("niq", "kln", 10, true),
+    ("nle", "luy", 10, true),
+    ("nn", "no", 20, false),
+    ("nso", "en", 30, true),
+    ("ny", "en", 30, true),
+    ("nyd", "luy", 10, true),
+    ("nyn", "en", 30, true),
+    ("oc", "fr", 20, true),
+    ("oki", "kln", 10, true),
+    ("om", "en", 30, true),
+    ("or", "en", 30, true),
+    ("orc", "om", 10, true),
+    ("orn", "ms", 10, true),
+    ("ors", "ms", 10, true),
+    ("pa", "en", 30, true),
+    ("pbt", "ps", 10, true),
+    ("pcm", "en", 20, true),
+    ("pel", "ms", 10, true),
+    ("pga", "ar", 10, true),
+    ("pko", "kln", 10, true),
+    ("ps", "en", 30, true),
+    ("pse", "ms", 10, true),
+    ("pst", "ps", 10, true),
+    ("qu", "es", 30, true),
+    ("qub", "qu", 10, true),
+    ("qud", "qu", 10, true),
+    ("quf", "qu", 10, true),
+    ("qug", "qu", 10, true),
+    ("quh", "qu", 10, true),
+    ("quk", "qu", 10, true),
+    ("qul", "qu", 10, true),
+    ("qup", "qu", 10, true),
+    ("qur", "qu", 10, true),
+    ("qus", "qu", 10, true),
+    ("quw", "qu", 10, true),
+    ("qux", "qu", 10, true),
+    ("quy", "qu", 10, true),
+    ("qva", "qu", 10, true),
+    ("qvc", "qu", 10, true),
+    ("qve", "qu", 10, true),
+    ("qvh", "qu", 10, true),
+    ("qvi", "qu", 10, true),
+    ("qvj", "qu", 10, true),
+    ("qvl", "qu", 10, true),
+    ("qvm", "qu", 10, true),
+    ("qvn", "qu", 10, true),
+    ("qvo", "qu", 10, true),
+    ("qvp", "qu", 10, true),
+    ("qvs", "qu", 10, true),
+    ("qvw", "qu", 10, true),
+    ("qvz", "qu", 10, true),
+    ("qwa", "qu", 10, true),
+    ("qwc", "qu", 10, true),
+    ("qwh", "qu", 10, true),
+    ("qws", "qu", 10, true),
+    ("qxa", "qu", 10, true),
+    ("qxc", "qu", 10, true),
+    ("qxh", "qu", 10, true),
+    ("qxl", "qu", 10, true),
+    ("qxn", "qu", 10, true),
+    ("qxo", "qu", 10, true),
+    ("qxp", "qu", 10, true),
+    ("qxr", "qu", 10, true),
+    ("qxt", "qu", 10, true),
+    ("qxu", "qu", 10, true),
+    ("qxw", "qu", 10, true),
+    ("rag", "luy", 10, true),
+    ("rm", "de", 20, true),
+    ("rn", "en", 30, true),
+    ("rw", "fr", 30, true),
+    ("sa", "hi", 30, true),
+    ("sd", "en", 30, true),
+    ("sdc", "sc", 10, true),
+    ("sdn", "sc", 10, true),
+    ("sgc", "kln", 10, true),
+    ("sh", "sr", 4, false),
+    ("shu", "ar", 10, true),
+    ("si", "en", 30, true),
+    ("skg", "mg", 10, true),
+    ("sn", "en", 30, true),
+    ("so", "en", 30, true),
+    ("spv", "or", 10, true),
+    ("sq", "en", 30, true),
+    ("sro", "sc", 10, true),
+    ("ssh", "ar", 10, true),
+    ("st", "en", 30, true),
+    ("su", "id", 20, true),
+    ("sw", "en", 30, true),
+    ("ta", "en", 30, true),
+    ("tdx", "mg", 10, true),
+    ("te", "en", 30, true),
+    ("tec", "kln", 10, true),
+    ("tg", "ru", 30, true),
+    ("ti", "en", 30, true),
+    ("tk", "ru", 30, true),
+    ("tkg", "mg", 10, true),
+    ("tlh", "en", 30, true),
+    ("tmw", "ms", 10, true),
+    ("tn", "en", 30, true),
+    ("to", "en", 30, true),
+    ("tt", "ru", 30, true),
+    ("tum", "en", 30, true),
+    ("tuy", "kln", 10, true),
+    ("txy", "mg", 10, true),
+    ("ug", "zh", 20, true),
+    ("uk", "ru", 20, true),
+    ("ur", "en", 30, true),
+    ("urk", "ms", 10, true),
+    ("uz", "ru", 30, true),
+    ("uzs", "uz", 10, true),
+    ("vkk", "ms", 10, true),
+    ("vkt", "ms", 10, true),
+    ("vro", "et", 10, true),
+    ("wo", "fr", 30, true),
+    ("wuu", "zh", 10, true),
+    ("xh", "en", 30, true),
+    ("xmm", "ms", 10, true),
+    ("xmv", "mg", 10, true),
+    ("xmw", "mg", 10, true),
+    ("yi", "en", 30, true),
+    ("yih", "yi", 10, true),
+    ("yo", "en", 30, true),
+    ("yue", "zh", 10, true),
+    ("za", "zh", 20, true),
+    ("zlm", "ms", 10, true),
+    ("zmi", "ms", 10, true),
+    ("zu", "en", 30, true),
+];
+
+/// Desired and supported language and script, distance, and whether the rule applies
+/// in only one direction.
+#[rustfmt::skip]
+pub(super) static SCRIPT_MATCHES: [(ScriptPattern, ScriptPattern, u8, bool); 49] = [
+    (("am", "Ethi"), ("en", "Latn"), 10, true),
+    (("ar", "Latn"), ("ar", "Arab"), 20, true),
+    (("az", "Latn"), ("ru", "Cyrl"), 10, true),
+    (("bn", "Latn"), ("bn", "Beng"), 20, true),
+    (("bn", "Beng"), ("en", "Latn"), 10, true),
+    (("bo", "Tibt"), ("zh", "Hans"), 10, true),
+    (("gu", "Latn"), ("gu", "Gujr"), 20, true),
+    (("hi", "Latn"), ("hi", "Deva"), 20, true),
+    (("hy", "Armn"), ("ru", "Cyrl"), 10, true),
+    (("ja", "Hani"), ("ja", "Jpan"), 5, true),
+    (("ja", "Hira"), ("ja", "Hrkt"), 5, true),
+    (("ja", "Hira"), ("ja", "Jpan"), 5, true),
+    (("ja", "Hrkt"), ("ja", "Jpan"), 5, true),
+    (("ja", "Kana"), ("ja", "Hrkt"), 5, true),
+    (("ja", "Kana"), ("ja", "Jpan"), 5, true),
+    (("ja", "Latn"), ("ja", "Jpan"), 5, true),
+    (("ka", "Geor"), ("en", "Latn"), 10, true),
+    (("km", "Khmr"), ("en", "Latn"), 10, true),
+    (("kn", "Knda"), ("en", "Latn"), 10, true),
+    (("kn", "Latn"), ("kn", "Knda"), 20, true),
+    (("ko", "Hang"), ("ko", "Kore"), 5, true),
+    (("ko", "Hani"), ("ko", "Kore"), 5, true),
+    (("ko", "Jamo"), ("ko", "Hang"), 5, true),
+    (("ko", "Jamo"), ("ko", "Kore"), 5, true),
+    (("lo", "Laoo"), ("en", "Latn"), 10, true),
+    (("ml", "Mlym"), ("en", "Latn"), 10, true),
+    (("ml", "Latn"), ("ml", "Mlym"), 20, true),
+    (("mr", "Latn"), ("mr", "Deva"), 20, true),
+    (("my", "Mymr"), ("en", "Latn"), 10, true),
+    (("ne", "Deva"), ("en", "Latn"), 10, true),
+    (("or", "Orya"), ("en", "Latn"), 10, true),
+    (("pa", "Guru"), ("en", "Latn"), 10, true),
+    (("ps", "Arab"), ("en", "Latn"), 10, true),
+    (("sd", "Arab"), ("en", "Latn"), 10, true),
+    (("si", "Sinh"), ("en", "Latn"), 10, true),
+    (("sr", "Cyrl"), ("sr", "Latn"), 5, false),
+    (("ta", "Taml"), ("en", "Latn"), 10, true),
+    (("ta", "Latn"), ("ta", "Taml"), 20, true),
+    (("te", "Telu"), ("en", "Latn"), 10, true),
+    (("te", "Latn"), ("te", "Telu"), 20, true),
+    (("ti", "Ethi"), ("en", "Latn"), 10, true),
+    (("tk", "Latn"), ("ru", "Cyrl"), 10, true),
+    (("ur", "Arab"), ("en", "Latn"), 10, true),
+    (("uz", "Latn"), ("ru", "Cyrl"), 10, true),
+    (("yi", "Hebr"), ("en", "Latn"), 10, true),
+    (("za", "Latn"), ("zh", "Hans"), 10, true),
+    (("zh", "Hani"), ("zh", "Hans"), 20, true),
+    (("zh", "Hani"), ("zh", "Hant"), 20, true),
+    (("zh", "Latn"), ("zh", "Hans"), 20, true),
+];
+
+/// Desired and supported language, script and region, distance, and whether the rule
+/// applies in only one direction; in order, the first matching rule applies. A script
+/// of `*` matches any script, a region of `*` any region, `$name` any region in the
+/// match variable `name` and `$!name` any region not in it.
+#[rustfmt::skip]
+pub(super) static REGION_MATCHES: [(RegionPattern, RegionPattern, u8, bool); 16] = [
+    (("ar", "*", "$maghreb"), ("ar", "*", "$maghreb"), 4, false),
+    (("ar", "*", "$!maghreb"), ("ar", "*", "$!maghreb"), 4, false),
+    (("ar", "*", "*"), ("ar", "*", "*"), 5, false),
+    (("en", "*", "$enUS"), ("en", "*", "$enUS"), 4, false),
+    (("en", "*", "$!enUS"), ("en", "*", "GB"), 3, false),
+    (("en", "*", "$!enUS"), ("en", "*", "$!enUS"), 4, false),
+    (("en", "*", "*"), ("en", "*", "*"), 5, false),
+    (("es", "*", "$americas"), ("es", "*", "$americas"), 4, false),
+    (("es", "*", "$!americas"), ("es", "*", "$!americas"), 4, false),
+    (("es", "*", "*"), ("es", "*", "*"), 5, false),
+    (("pt", "*", "$americas"), ("pt", "*", "$americas"), 4, false),
+    (("pt", "*", "$!americas"), ("pt", "*", "$!americas"), 4, false),
+    (("pt", "*", "*"), ("pt", "*", "*"), 5, false),
+    (("zh", "Hant", "$cnsar"), ("zh", "Hant", "$cnsar"), 4, false),
+    (("zh", "Hant", "$!cnsar"), ("zh", "Hant", "$!cnsar"), 4, false),
+    (("zh", "Hant", "*"), ("zh", "Hant", "*"), 5, false),
+];
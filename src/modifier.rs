@@ -0,0 +1,394 @@
+/*!
+The `Modifier` type provides a structure for the modifier component of a locale identifier.
+
+## Standards
+
+The modifier of a POSIX locale identifier, the part following the `@` character, is a
+semi-colon separated list of _identifiers_, or _name '=' value_ pairs. In practice there
+are two common forms:
+
+* A single bare identifier, for example `@euro`, `@latin` or `@Latn`; these are commonly
+  used to denote the script in use, or some variant of the locale.
+* A list of `key=value` pairs, for example `@collation=pinyin;currency=CNY`.
+
+Both forms may also be mixed in a single modifier, and a `Modifier` retains the order
+of its entries so that it is displayed exactly as it was parsed or constructed.
+
+* `identifier`, and `key` = `[a-zA-Z0-9_\-]+`.
+* `value` = `[a-zA-Z0-9_\-.+]+`.
+
+## Examples
+
+```
+use locale_types::Modifier;
+use std::str::FromStr;
+
+let mut modifier = Modifier::from_str("collation=pinyin;currency=CNY").unwrap();
+assert_eq!(modifier.get("currency"), Some("CNY"));
+
+modifier.insert("currency".to_string(), "EUR".to_string()).unwrap();
+modifier.insert_identifier("euro".to_string()).unwrap();
+assert_eq!(modifier.to_string(), "collation=pinyin;currency=EUR;euro");
+
+assert_eq!(modifier.remove("collation"), Some("pinyin".to_string()));
+assert_eq!(modifier.to_string(), "currency=EUR;euro");
+```
+
+*/
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::string::{ParseError, ParseErrorKind};
+use crate::{LocaleError, LocaleResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A `Modifier` is an ordered list of entries, each of which is either a bare
+/// identifier or a `key=value` pair.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Modifier {
+    entries: Vec<(String, Option<String>)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - Modifier
+// ------------------------------------------------------------------------------------------------
+
+const SEP_ENTRY: char = ';';
+const SEP_VALUE: char = '=';
+
+impl Modifier {
+    /// Construct a new, empty, modifier.
+    pub fn new() -> Self {
+        Modifier {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Construct a new modifier consisting of the single bare `identifier`.
+    pub fn from_identifier(identifier: String) -> LocaleResult<Self> {
+        let mut modifier = Modifier::new();
+        modifier.insert_identifier(identifier)?;
+        Ok(modifier)
+    }
+
+    /// Returns `true` if this modifier has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the number of entries, identifiers and pairs, in this modifier.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this modifier consists of a single bare identifier,
+    /// such as `euro` or `Latn`.
+    pub fn is_identifier(&self) -> bool {
+        self.entries.len() == 1 && self.entries[0].1.is_none()
+    }
+
+    /// Return the single bare identifier if this modifier `is_identifier()`.
+    pub fn identifier(&self) -> Option<&str> {
+        if self.is_identifier() {
+            Some(&self.entries[0].0)
+        } else {
+            None
+        }
+    }
+
+    /// Return an iterator over the entries of this modifier, in order. Bare
+    /// identifiers are returned with a value of `None`.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_deref()))
+    }
+
+    /// Return an iterator over the keys of the `key=value` pairs, in order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .filter(|(_, value)| value.is_some())
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// Return an iterator over the bare identifiers, in order.
+    pub fn identifiers(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .filter(|(_, value)| value.is_none())
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// Return the value associated with `key`, if present.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, v)| k == key && v.is_some())
+            .and_then(|(_, v)| v.as_deref())
+    }
+
+    /// Returns `true` if there is a `key=value` pair with the given `key`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns `true` if there is a bare identifier equal to `identifier`.
+    pub fn contains_identifier(&self, identifier: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|(k, v)| k == identifier && v.is_none())
+    }
+
+    /// Insert a `key=value` pair. If the key is already present its value is
+    /// replaced, in place, and the previous value returned; otherwise the new
+    /// pair is added to the end of the modifier.
+    pub fn insert(&mut self, key: String, value: String) -> LocaleResult<Option<String>> {
+        if !is_valid_key(&key) || !is_valid_value(&value) {
+            return Err(LocaleError::InvalidModifier);
+        }
+        match self
+            .entries
+            .iter_mut()
+            .find(|(k, v)| *k == key && v.is_some())
+        {
+            Some((_, existing)) => Ok(existing.replace(value)),
+            None => {
+                self.entries.push((key, Some(value)));
+                Ok(None)
+            }
+        }
+    }
+
+    /// Insert a bare identifier, if it is not already present it is added to the
+    /// end of the modifier.
+    pub fn insert_identifier(&mut self, identifier: String) -> LocaleResult<()> {
+        if !is_valid_key(&identifier) {
+            return Err(LocaleError::InvalidModifier);
+        }
+        if !self.contains_identifier(&identifier) {
+            self.entries.push((identifier, None));
+        }
+        Ok(())
+    }
+
+    /// Sort the entries of this modifier by key, or identifier; the relative order
+    /// of entries with the same key is preserved.
+    pub fn sort(&mut self) {
+        self.entries.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+    }
+
+    /// Remove the `key=value` pair with the given `key`, returning its value.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let index = self
+            .entries
+            .iter()
+            .position(|(k, v)| k == key && v.is_some())?;
+        self.entries.remove(index).1
+    }
+
+    /// Remove the bare identifier equal to `identifier`, returning `true` if it
+    /// was present.
+    pub fn remove_identifier(&mut self, identifier: &str) -> bool {
+        match self
+            .entries
+            .iter()
+            .position(|(k, v)| k == identifier && v.is_none())
+        {
+            Some(index) => {
+                let _ = self.entries.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Display for Modifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.entries
+                .iter()
+                .map(|(key, value)| match value {
+                    Some(value) => format!("{}{}{}", key, SEP_VALUE, value),
+                    None => key.clone(),
+                })
+                .collect::<Vec<String>>()
+                .join(&SEP_ENTRY.to_string())
+        )
+    }
+}
+
+impl FromStr for Modifier {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::EmptyString, s, 0..0));
+        }
+        let mut modifier = Modifier::new();
+        let mut offset = 0;
+        for entry in s.split(SEP_ENTRY) {
+            let mut parts = entry.splitn(2, SEP_VALUE);
+            let key = parts.next().unwrap();
+            let result = match parts.next() {
+                Some(value) if !modifier.contains_key(key) => modifier
+                    .insert(key.to_string(), value.to_string())
+                    .map(|_| ()),
+                None if !modifier.contains_identifier(key) => {
+                    modifier.insert_identifier(key.to_string())
+                }
+                _ => Err(LocaleError::InvalidModifier),
+            };
+            if result.is_err() {
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidModifier,
+                    s,
+                    offset..offset + entry.len(),
+                ));
+            }
+            offset += entry.len() + 1;
+        }
+        Ok(modifier)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn is_valid_value(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.' || c == '+')
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::string::{ParseError, ParseErrorKind};
+    use crate::{LocaleError, Modifier};
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_identifier() {
+        let modifier = Modifier::from_str("euro").unwrap();
+        assert!(modifier.is_identifier());
+        assert_eq!(modifier.identifier(), Some("euro"));
+        assert_eq!(modifier.get("euro"), None);
+        assert!(modifier.contains_identifier("euro"));
+        assert_eq!(modifier.to_string(), "euro");
+    }
+
+    #[test]
+    fn test_pairs() {
+        let modifier = Modifier::from_str("collation=pinyin;currency=CNY").unwrap();
+        assert!(!modifier.is_identifier());
+        assert_eq!(modifier.len(), 2);
+        assert_eq!(modifier.get("collation"), Some("pinyin"));
+        assert_eq!(modifier.get("currency"), Some("CNY"));
+        assert_eq!(
+            modifier.keys().collect::<Vec<&str>>(),
+            vec!["collation", "currency"]
+        );
+        assert_eq!(modifier.to_string(), "collation=pinyin;currency=CNY");
+    }
+
+    #[test]
+    fn test_mixed_ordering() {
+        let modifier = Modifier::from_str("zzz=1;euro;aaa=2").unwrap();
+        assert_eq!(
+            modifier.iter().collect::<Vec<(&str, Option<&str>)>>(),
+            vec![("zzz", Some("1")), ("euro", None), ("aaa", Some("2"))]
+        );
+        assert_eq!(modifier.identifiers().collect::<Vec<&str>>(), vec!["euro"]);
+        assert_eq!(modifier.to_string(), "zzz=1;euro;aaa=2");
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut modifier = Modifier::new();
+        assert!(modifier.is_empty());
+        assert_eq!(
+            modifier.insert("currency".to_string(), "CNY".to_string()),
+            Ok(None)
+        );
+        assert_eq!(
+            modifier.insert("collation".to_string(), "pinyin".to_string()),
+            Ok(None)
+        );
+        assert_eq!(
+            modifier.insert("currency".to_string(), "EUR".to_string()),
+            Ok(Some("CNY".to_string()))
+        );
+        assert_eq!(modifier.to_string(), "currency=EUR;collation=pinyin");
+        assert_eq!(modifier.remove("currency"), Some("EUR".to_string()));
+        assert_eq!(modifier.remove("currency"), None);
+        assert_eq!(modifier.to_string(), "collation=pinyin");
+
+        modifier.insert_identifier("euro".to_string()).unwrap();
+        assert!(modifier.remove_identifier("euro"));
+        assert!(!modifier.remove_identifier("euro"));
+    }
+
+    #[test]
+    fn test_bad_entries() {
+        let mut modifier = Modifier::new();
+        assert_eq!(
+            modifier.insert("".to_string(), "x".to_string()),
+            Err(LocaleError::InvalidModifier)
+        );
+        assert_eq!(
+            modifier.insert("a;b".to_string(), "x".to_string()),
+            Err(LocaleError::InvalidModifier)
+        );
+        assert_eq!(
+            modifier.insert_identifier("a=b".to_string()),
+            Err(LocaleError::InvalidModifier)
+        );
+    }
+
+    #[test]
+    fn test_bad_from_str() {
+        let error = |s, kind, span| Err(ParseError::new(kind, s, span));
+        assert_eq!(
+            Modifier::from_str(""),
+            error("", ParseErrorKind::EmptyString, 0..0)
+        );
+        assert_eq!(
+            Modifier::from_str("a;;b"),
+            error("a;;b", ParseErrorKind::InvalidModifier, 2..2)
+        );
+        assert_eq!(
+            Modifier::from_str("a="),
+            error("a=", ParseErrorKind::InvalidModifier, 0..2)
+        );
+        assert_eq!(
+            Modifier::from_str("a=b=c"),
+            error("a=b=c", ParseErrorKind::InvalidModifier, 0..5)
+        );
+        assert_eq!(
+            Modifier::from_str("a=b;a=c"),
+            error("a=b;a=c", ParseErrorKind::InvalidModifier, 4..7)
+        );
+    }
+}
@@ -0,0 +1,428 @@
+/*!
+Provides locale negotiation using the matching schemes of
+[RFC 4647](https://tools.ietf.org/html/rfc4647), _Matching of Language Tags_.
+
+A user's preferences are expressed as a priority list of
+[`LanguageRange`](struct.LanguageRange.html) values, most preferred first, and
+are matched against the locales an application has available. Three schemes are
+provided:
+
+* [`filter_basic`](fn.filter_basic.html), RFC 4647 section 3.3.1; a range matches a
+  locale if it is equal to, or a prefix of, the locale. The range `*` matches all locales.
+* [`filter_extended`](fn.filter_extended.html), RFC 4647 section 3.3.2; as basic
+  filtering but a `*` subtag, as in `de-*-DE`, matches any sequence of subtags.
+* [`lookup`](fn.lookup.html), RFC 4647 section 3.4; returns the single best locale,
+  progressively truncating each range until a locale matches, or the given default.
+
+Available values are compared in their BCP 47 form, so that the `LocaleString`
+`sr_RS.UTF-8@latin` is matched as `sr-Latn-RS`; the code set and any modifier
+without a BCP 47 equivalent are ignored. The POSIX minimal locale and paths are
+only matched by the `*` range. Results are always ordered first by the priority of
+the range that matched, and then by the order of the available values, so that
+negotiation is deterministic.
+
+## Examples
+
+```
+use locale_types::negotiate::{filter_basic, lookup, LanguageRange};
+use locale_types::{Locale, LocaleString};
+use std::str::FromStr;
+
+let preferences: Vec<LanguageRange> = ["fr-CA", "en"]
+    .iter()
+    .map(|s| LanguageRange::from_str(s).unwrap())
+    .collect();
+let available: Vec<Locale> = ["C", "en_US.UTF-8", "en_GB.UTF-8", "fr_FR.UTF-8"]
+    .iter()
+    .map(|s| Locale::from_str(s).unwrap())
+    .collect();
+
+let matched = filter_basic(&preferences, &available);
+assert_eq!(matched.len(), 2);
+assert_eq!(matched[0].to_string(), "en_US.UTF-8");
+
+let preferences = [LanguageRange::from_str("en-GB-oxendict").unwrap()];
+let best = lookup(&preferences, &available, Locale::posix());
+assert_eq!(best.to_string(), "en_GB.UTF-8");
+
+let preferences = [LanguageRange::from_str("fr").unwrap()];
+assert_eq!(lookup(&preferences, &available, Locale::posix()), Locale::posix());
+```
+*/
+
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::convert::to_language_tag;
+use crate::string::{ParseError, ParseErrorKind};
+use crate::{LanguageTag, Locale, LocaleString};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// An RFC 4647 language range, a language tag that may contain the wildcard `*`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageRange {
+    subtags: Vec<String>,
+}
+
+/// Implemented by values that may be matched against a `LanguageRange`.
+pub trait Negotiable: Clone {
+    /// Return the lower-case BCP 47 subtags used to match this value, or `None`
+    /// if this value is only matched by the wildcard range `*`.
+    fn subtags(&self) -> Option<Vec<String>>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+/// RFC 4647 basic filtering; return all `available` values matched by any of the
+/// `ranges`, ordered by the first range that matched each value. Any `*` subtags
+/// after the first in a range are treated as in extended filtering.
+pub fn filter_basic<T>(ranges: &[LanguageRange], available: &[T]) -> Vec<T>
+where
+    T: Negotiable,
+{
+    filter(ranges, available, |range, subtags| {
+        if range.is_basic() {
+            range.subtags.len() <= subtags.len()
+                && range.subtags.iter().zip(subtags).all(|(r, s)| r == s)
+        } else {
+            matches_extended(&range.subtags, subtags)
+        }
+    })
+}
+
+/// RFC 4647 extended filtering; return all `available` values matched by any of the
+/// `ranges`, ordered by the first range that matched each value.
+pub fn filter_extended<T>(ranges: &[LanguageRange], available: &[T]) -> Vec<T>
+where
+    T: Negotiable,
+{
+    filter(ranges, available, |range, subtags| {
+        matches_extended(&range.subtags, subtags)
+    })
+}
+
+/// RFC 4647 lookup; return the single value from `available` that best matches
+/// `ranges`, or `default` if there is none. Wildcard subtags are removed from each
+/// range, and the range `*` is ignored.
+pub fn lookup<T>(ranges: &[LanguageRange], available: &[T], default: T) -> T
+where
+    T: Negotiable,
+{
+    let candidates: Vec<(&T, Vec<String>)> = available
+        .iter()
+        .filter_map(|value| value.subtags().map(|subtags| (value, subtags)))
+        .collect();
+    for range in ranges.iter().filter(|range| !range.is_wildcard()) {
+        let mut subtags: Vec<&String> = range.subtags.iter().filter(|s| *s != WILDCARD).collect();
+        while !subtags.is_empty() {
+            if let Some((value, _)) = candidates.iter().find(|(_, candidate)| {
+                candidate.len() == subtags.len()
+                    && candidate.iter().zip(subtags.iter()).all(|(c, s)| c == *s)
+            }) {
+                return (*value).clone();
+            }
+            subtags.pop();
+            if subtags.last().is_some_and(|s| s.len() == 1) {
+                subtags.pop();
+            }
+        }
+    }
+    default
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - LanguageRange
+// ------------------------------------------------------------------------------------------------
+
+const WILDCARD: &str = "*";
+const SEP_SUBTAG: char = '-';
+
+impl LanguageRange {
+    /// Return the range `*`, which matches all values.
+    pub fn wildcard() -> Self {
+        LanguageRange {
+            subtags: vec![WILDCARD.to_string()],
+        }
+    }
+
+    /// Return the subtags of this range, in lower-case.
+    pub fn subtags(&self) -> Vec<String> {
+        self.subtags.clone()
+    }
+
+    /// Returns `true` if this is the range `*`.
+    pub fn is_wildcard(&self) -> bool {
+        self.subtags.len() == 1 && self.subtags[0] == WILDCARD
+    }
+
+    /// Returns `true` if this is a basic language range, either `*` or a range
+    /// with no wildcard subtags.
+    pub fn is_basic(&self) -> bool {
+        self.is_wildcard() || !self.subtags.iter().any(|s| s == WILDCARD)
+    }
+}
+
+impl Display for LanguageRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.subtags.join(&SEP_SUBTAG.to_string()))
+    }
+}
+
+impl FromStr for LanguageRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::EmptyString, s, 0..0));
+        }
+        let mut subtags: Vec<String> = Vec::new();
+        let mut offset = 0;
+        for (index, subtag) in s.split(SEP_SUBTAG).enumerate() {
+            let valid = subtag == WILDCARD
+                || (!subtag.is_empty()
+                    && subtag.len() <= 8
+                    && if index == 0 {
+                        subtag.chars().all(|c| c.is_ascii_alphabetic())
+                    } else {
+                        subtag.chars().all(|c| c.is_ascii_alphanumeric())
+                    });
+            if !valid {
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidLanguageRange,
+                    s,
+                    offset..offset + subtag.len(),
+                ));
+            }
+            subtags.push(subtag.to_ascii_lowercase());
+            offset += subtag.len() + 1;
+        }
+        Ok(LanguageRange { subtags })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - Negotiable
+// ------------------------------------------------------------------------------------------------
+
+impl Negotiable for LanguageTag {
+    fn subtags(&self) -> Option<Vec<String>> {
+        Some(split_tag(&self.to_string()))
+    }
+}
+
+impl Negotiable for LocaleString {
+    fn subtags(&self) -> Option<Vec<String>> {
+        Some(split_tag(&to_language_tag(self).value().to_string()))
+    }
+}
+
+impl Negotiable for Locale {
+    fn subtags(&self) -> Option<Vec<String>> {
+        match self {
+            Locale::String(locale) => locale.subtags(),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn split_tag(tag: &str) -> Vec<String> {
+    tag.to_ascii_lowercase()
+        .split(SEP_SUBTAG)
+        .map(str::to_string)
+        .collect()
+}
+
+fn filter<T, F>(ranges: &[LanguageRange], available: &[T], matches: F) -> Vec<T>
+where
+    T: Negotiable,
+    F: Fn(&LanguageRange, &[String]) -> bool,
+{
+    let mut selected: Vec<bool> = vec![false; available.len()];
+    let mut results: Vec<T> = Vec::new();
+    for range in ranges {
+        for (index, value) in available.iter().enumerate() {
+            if selected[index] {
+                continue;
+            }
+            let matched = range.is_wildcard()
+                || value
+                    .subtags()
+                    .is_some_and(|subtags| matches(range, &subtags));
+            if matched {
+                selected[index] = true;
+                results.push(value.clone());
+            }
+        }
+    }
+    results
+}
+
+/// The extended filtering algorithm from RFC 4647 section 3.3.2.
+fn matches_extended(range: &[String], tag: &[String]) -> bool {
+    match (range.first(), tag.first()) {
+        (Some(r), Some(t)) if r == WILDCARD || r == t => (),
+        _ => return false,
+    }
+    let mut range = range[1..].iter().peekable();
+    let mut tag = tag[1..].iter().peekable();
+    while let Some(r) = range.peek() {
+        if *r == WILDCARD {
+            range.next();
+        } else {
+            match tag.peek() {
+                None => return false,
+                Some(t) if t == r => {
+                    range.next();
+                    tag.next();
+                }
+                Some(t) if t.len() == 1 => return false,
+                Some(_) => {
+                    tag.next();
+                }
+            }
+        }
+    }
+    true
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn ranges(ranges: &[&str]) -> Vec<LanguageRange> {
+        ranges
+            .iter()
+            .map(|s| LanguageRange::from_str(s).unwrap())
+            .collect()
+    }
+
+    fn tags(tags: &[&str]) -> Vec<LanguageTag> {
+        tags.iter()
+            .map(|s| LanguageTag::from_str(s).unwrap())
+            .collect()
+    }
+
+    fn to_strings<T: Display>(values: Vec<T>) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_range_from_str() {
+        let range = LanguageRange::from_str("de-*-DE").unwrap();
+        assert_eq!(range.to_string(), "de-*-de");
+        assert!(!range.is_basic());
+        assert!(LanguageRange::from_str("*").unwrap().is_wildcard());
+        assert!(LanguageRange::from_str("en-US").unwrap().is_basic());
+        assert_eq!(
+            LanguageRange::from_str("en--US"),
+            Err(ParseError::new(
+                ParseErrorKind::InvalidLanguageRange,
+                "en--US",
+                3..3
+            ))
+        );
+        assert!(LanguageRange::from_str("1en").is_err());
+        assert!(LanguageRange::from_str("en-abcdefghi").is_err());
+        assert!(LanguageRange::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_filter_basic() {
+        let available = tags(&["de-DE", "de-Latn-DE", "de-CH", "en-US", "de"]);
+        assert_eq!(
+            to_strings(filter_basic(&ranges(&["de-DE"]), &available)),
+            vec!["de-DE"]
+        );
+        assert_eq!(
+            to_strings(filter_basic(&ranges(&["en", "de"]), &available)),
+            vec!["en-US", "de-DE", "de-Latn-DE", "de-CH", "de"]
+        );
+        assert_eq!(filter_basic(&ranges(&["*"]), &available).len(), 5);
+        assert!(filter_basic(&ranges(&["fr"]), &available).is_empty());
+    }
+
+    #[test]
+    fn test_filter_extended() {
+        let available = tags(&[
+            "de-DE",
+            "de-de",
+            "de-Latn-DE",
+            "de-Latf-DE",
+            "de-DE-x-goethe",
+            "de-Latn-DE-1996",
+            "de-Deva-DE",
+            "de",
+            "de-x-DE",
+            "de-Deva",
+        ]);
+        assert_eq!(
+            to_strings(filter_extended(&ranges(&["de-*-DE"]), &available)),
+            vec![
+                "de-DE",
+                "de-DE",
+                "de-Latn-DE",
+                "de-Latf-DE",
+                "de-DE-x-goethe",
+                "de-Latn-DE-1996",
+                "de-Deva-DE"
+            ]
+        );
+        assert_eq!(
+            to_strings(filter_extended(&ranges(&["*-DE"]), &available)).len(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_lookup() {
+        let available = tags(&["de", "de-CH", "zh-Hant", "en"]);
+        let default = LanguageTag::from_str("en").unwrap();
+        let best = |r: &[&str]| lookup(&ranges(r), &available, default.clone()).to_string();
+        assert_eq!(best(&["zh-Hant-CN-x-private1-private2"]), "zh-Hant");
+        assert_eq!(best(&["de-CH-1996"]), "de-CH");
+        assert_eq!(best(&["fr-FR", "de-AT"]), "de");
+        assert_eq!(best(&["fr", "*"]), "en");
+        assert_eq!(best(&[]), "en");
+    }
+
+    #[test]
+    fn test_locales() {
+        let available: Vec<Locale> = ["POSIX", "sr_RS.UTF-8@latin", "sr_RS.UTF-8", "en_GB"]
+            .iter()
+            .map(|s| Locale::from_str(s).unwrap())
+            .collect();
+        assert_eq!(
+            to_strings(filter_basic(&ranges(&["sr-Latn"]), &available)),
+            vec!["sr_RS.UTF-8@latin"]
+        );
+        assert_eq!(
+            to_strings(filter_basic(&ranges(&["en", "*"]), &available)),
+            vec!["en_GB", "C", "sr_RS.UTF-8@latin", "sr_RS.UTF-8"]
+        );
+        assert_eq!(
+            lookup(&ranges(&["sr-RS"]), &available, Locale::posix()).to_string(),
+            "sr_RS.UTF-8"
+        );
+        assert_eq!(
+            lookup(&ranges(&["fr"]), &available, Locale::posix()),
+            Locale::posix()
+        );
+    }
+}
@@ -0,0 +1,442 @@
+/*!
+Provides a safe interface to the C library functions that set and query the locale
+of the process, `setlocale(3)`, and of the current thread, `newlocale(3)` and
+`uselocale(3)`. This module is only available on Linux, and requires the `os`
+feature.
+
+## Thread Safety
+
+The process locale is global state, and `setlocale(3)` is not thread-safe. The
+functions in this module that set or query the process locale are serialized by a
+lock, and so may be called from any thread; however the lock cannot extend to other
+code that calls `setlocale(3)` directly. A multi-threaded program should set the
+process locale once at startup, or use a `ThreadLocale` which only affects the
+thread on which it is active.
+
+## Examples
+
+```
+use locale_types::os::{current_locale, set_locale, ThreadLocale};
+use locale_types::{Locale, LocaleCategory};
+use std::str::FromStr;
+
+let c_utf_8 = Locale::from_str("C.UTF-8").unwrap();
+
+set_locale(LocaleCategory::All, &Locale::posix()).unwrap();
+assert_eq!(current_locale(LocaleCategory::Numeric).unwrap(), Locale::posix());
+
+let thread_locale = ThreadLocale::new(LocaleCategory::All, &c_utf_8).unwrap();
+let result = thread_locale.with_active(|| {
+    // locale-dependent C library calls on this thread now use C.UTF-8.
+    42
+});
+assert_eq!(result.unwrap(), 42);
+// the thread has returned to its previous locale.
+```
+*/
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::str::FromStr;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::category::LocaleCategory;
+use crate::locale::Locale;
+use crate::settings::LocaleSettings;
+use crate::{LocaleError, LocaleResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A locale object, created by `newlocale(3)`, which may be made the locale of the
+/// current thread with `with_active`. The object is freed when dropped.
+#[derive(Debug)]
+pub struct ThreadLocale {
+    handle: libc::locale_t,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Set the locale of `category` for the whole process.
+pub fn set_locale(category: LocaleCategory, locale: &Locale) -> LocaleResult<()> {
+    let name = locale_name(locale)?;
+    let category_value = platform_value(category)?;
+    let _lock = lock_process_locale();
+    // SAFETY: `name` is a valid, NUL-terminated, string for the duration of the call,
+    // and calls to `setlocale` are serialized by the lock.
+    let result = unsafe { libc::setlocale(category_value, name.as_ptr()) };
+    if result.is_null() {
+        Err(LocaleError::OSError(format!(
+            "setlocale({}, \"{}\") failed, the locale is not supported",
+            category, locale
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Set the locale of `category` for the whole process from the environment, following
+/// the rules described in the `env` module.
+pub fn set_locale_from_environment(category: LocaleCategory) -> LocaleResult<()> {
+    let category_value = platform_value(category)?;
+    let _lock = lock_process_locale();
+    // SAFETY: the empty string is a valid, NUL-terminated, string, and calls to
+    // `setlocale` are serialized by the lock.
+    let result = unsafe { libc::setlocale(category_value, EMPTY.as_ptr() as *const c_char) };
+    if result.is_null() {
+        Err(LocaleError::OSError(format!(
+            "setlocale({}, \"\") failed, the locale in the environment is not supported",
+            category
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Return the locale of `category` for the whole process. For `LocaleCategory::All`
+/// the categories must have the same locale, otherwise `LocaleError::Unsupported`
+/// is returned and `current_settings` should be used.
+pub fn current_locale(category: LocaleCategory) -> LocaleResult<Locale> {
+    let name = query_locale(category)?;
+    if category == LocaleCategory::All {
+        LocaleSettings::from_str(&name)?
+            .collapse()
+            .ok_or(LocaleError::Unsupported)
+    } else {
+        Ok(Locale::from_str(&name)?)
+    }
+}
+
+/// Return the locale of each category for the whole process.
+pub fn current_settings() -> LocaleResult<LocaleSettings> {
+    LocaleSettings::from_str(&query_locale(LocaleCategory::All)?)
+}
+
+/// Return the locale of `category` for the current thread; this is the locale of
+/// an active `ThreadLocale` if there is one, otherwise the locale of the process.
+/// `LocaleCategory::All` is not supported.
+#[cfg(target_env = "gnu")]
+pub fn current_thread_locale(category: LocaleCategory) -> LocaleResult<Locale> {
+    // SAFETY: a null locale queries, and does not change, the locale of the thread.
+    let handle = unsafe { libc::uselocale(ptr::null_mut()) };
+    if handle == LC_GLOBAL_LOCALE {
+        current_locale(category)
+    } else {
+        query_thread_locale(handle, category)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - ThreadLocale
+// ------------------------------------------------------------------------------------------------
+
+impl ThreadLocale {
+    /// Create a new locale object where `category` has the locale `locale`, and all
+    /// other categories have the minimal locale.
+    pub fn new(category: LocaleCategory, locale: &Locale) -> LocaleResult<Self> {
+        let handle = new_locale(category, locale, ptr::null_mut())?;
+        Ok(ThreadLocale { handle })
+    }
+
+    /// Return a new locale object where `category` has the locale `locale`, and all
+    /// other categories are unchanged. On failure this locale object is freed.
+    pub fn with(self, category: LocaleCategory, locale: &Locale) -> LocaleResult<Self> {
+        let handle = new_locale(category, locale, self.handle)?;
+        // The base locale object is now owned by, and may be, `handle`.
+        std::mem::forget(self);
+        Ok(ThreadLocale { handle })
+    }
+
+    /// Make this locale object the locale of the current thread while calling `f`,
+    /// returning the result of `f`. The previous locale of the thread is restored
+    /// before returning, or if `f` panics.
+    pub fn with_active<F, R>(&self, f: F) -> LocaleResult<R>
+    where
+        F: FnOnce() -> R,
+    {
+        // SAFETY: `handle` is a valid locale object, and the borrow of `self` ensures
+        // it outlives its use by this thread, which ends when `_restore` is dropped.
+        let previous = unsafe { libc::uselocale(self.handle) };
+        if previous.is_null() {
+            return Err(LocaleError::OSError(format!(
+                "uselocale failed: {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+        let _restore = RestoreThreadLocale { previous };
+        Ok(f())
+    }
+
+    /// Return the locale of `category` in this locale object; `LocaleCategory::All`
+    /// is not supported.
+    #[cfg(target_env = "gnu")]
+    pub fn locale(&self, category: LocaleCategory) -> LocaleResult<Locale> {
+        query_thread_locale(self.handle, category)
+    }
+}
+
+impl Drop for ThreadLocale {
+    fn drop(&mut self) {
+        // SAFETY: `handle` was returned by `newlocale`, and is not the locale of any
+        // thread, as `with_active` borrows it and restores the previous locale before
+        // returning.
+        unsafe { libc::freelocale(self.handle) }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+/// Restores the previous locale of the thread at the end of `ThreadLocale::with_active`,
+/// including when unwinding; it is never exposed, so restores are always made in the
+/// reverse order of activation.
+struct RestoreThreadLocale {
+    previous: libc::locale_t,
+}
+
+impl Drop for RestoreThreadLocale {
+    fn drop(&mut self) {
+        // SAFETY: `previous` was returned by `uselocale`, and so is either the global
+        // locale or the locale object of an enclosing `with_active`, which is borrowed
+        // for longer than this value lives.
+        let _ = unsafe { libc::uselocale(self.previous) };
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const EMPTY: &[u8] = b"\0";
+
+static PROCESS_LOCALE: Mutex<()> = Mutex::new(());
+
+/// The special value of `locale_t` denoting the process locale, `(locale_t) -1`.
+const LC_GLOBAL_LOCALE: libc::locale_t = -1isize as libc::locale_t;
+
+fn platform_value(category: LocaleCategory) -> LocaleResult<c_int> {
+    category.platform_value().ok_or(LocaleError::UnsetCategory)
+}
+
+fn platform_mask(category: LocaleCategory) -> LocaleResult<c_int> {
+    if category == LocaleCategory::All {
+        Ok(libc::LC_ALL_MASK)
+    } else {
+        Ok(1 << platform_value(category)?)
+    }
+}
+
+fn locale_name(locale: &Locale) -> LocaleResult<CString> {
+    CString::new(locale.to_string()).map_err(|_| LocaleError::InvalidLocaleString)
+}
+
+fn new_locale(
+    category: LocaleCategory,
+    locale: &Locale,
+    base: libc::locale_t,
+) -> LocaleResult<libc::locale_t> {
+    let name = locale_name(locale)?;
+    // SAFETY: `name` is a valid, NUL-terminated, string for the duration of the call,
+    // and `base` is either null or a valid locale object.
+    let handle = unsafe { libc::newlocale(platform_mask(category)?, name.as_ptr(), base) };
+    if handle.is_null() {
+        Err(LocaleError::OSError(format!(
+            "newlocale({}, \"{}\") failed: {}",
+            category,
+            locale,
+            std::io::Error::last_os_error()
+        )))
+    } else {
+        Ok(handle)
+    }
+}
+
+/// Serializes calls to `setlocale`; the lock is also held while the name it returns
+/// is copied, as that name is overwritten by the next call.
+fn lock_process_locale() -> MutexGuard<'static, ()> {
+    PROCESS_LOCALE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn query_locale(category: LocaleCategory) -> LocaleResult<String> {
+    let category_value = platform_value(category)?;
+    let _lock = lock_process_locale();
+    // SAFETY: a null locale queries, and does not change, the locale, and calls to
+    // `setlocale` are serialized by the lock.
+    let result = unsafe { libc::setlocale(category_value, ptr::null()) };
+    to_string(result, || format!("setlocale({}, NULL) failed", category))
+}
+
+/// Query the name of a category using the glibc `_NL_LOCALE_NAME` item.
+#[cfg(target_env = "gnu")]
+fn query_thread_locale(handle: libc::locale_t, category: LocaleCategory) -> LocaleResult<Locale> {
+    if category == LocaleCategory::All {
+        return Err(LocaleError::UnsetCategory);
+    }
+    let item = (platform_value(category)? << 16) | 0xFFFF;
+    // SAFETY: `handle` is a valid locale object, and the item is valid for glibc.
+    let result = unsafe { libc::nl_langinfo_l(item as libc::nl_item, handle) };
+    let name = to_string(result, || {
+        format!("nl_langinfo_l(_NL_LOCALE_NAME({}))", category)
+    })?;
+    Ok(Locale::from_str(&name)?)
+}
+
+fn to_string<F>(result: *const c_char, describe: F) -> LocaleResult<String>
+where
+    F: FnOnce() -> String,
+{
+    if result.is_null() {
+        return Err(LocaleError::OSError(describe()));
+    }
+    // SAFETY: the C library returned a valid, NUL-terminated, string which remains
+    // valid until the next call that changes the locale; it is copied immediately.
+    let name = unsafe { CStr::from_ptr(result) };
+    match name.to_str() {
+        Ok(name) if !name.is_empty() => Ok(name.to_string()),
+        _ => Err(LocaleError::OSError(format!(
+            "{} returned an invalid name",
+            describe()
+        ))),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::sync::Mutex;
+
+    use super::*;
+
+    // The process locale is shared by all tests in this module.
+    static TEST_SEQUENCE: Mutex<()> = Mutex::new(());
+
+    fn locale(s: &str) -> Locale {
+        Locale::from_str(s).unwrap()
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_set_and_query() {
+        let _lock = TEST_SEQUENCE.lock().unwrap();
+        set_locale(LocaleCategory::All, &Locale::posix()).unwrap();
+        assert_eq!(
+            current_locale(LocaleCategory::All).unwrap(),
+            Locale::posix()
+        );
+
+        set_locale(LocaleCategory::CharacterType, &locale("C.UTF-8")).unwrap();
+        assert_eq!(
+            current_locale(LocaleCategory::CharacterType).unwrap(),
+            locale("C.UTF-8")
+        );
+        assert_eq!(
+            current_locale(LocaleCategory::All),
+            Err(LocaleError::Unsupported)
+        );
+        let settings = current_settings().unwrap();
+        assert_eq!(
+            settings.get(LocaleCategory::CharacterType),
+            Some(&locale("C.UTF-8"))
+        );
+        assert_eq!(settings.get(LocaleCategory::Time), Some(&Locale::posix()));
+
+        set_locale(LocaleCategory::All, &Locale::posix()).unwrap();
+    }
+
+    #[test]
+    fn test_set_unknown() {
+        let _lock = TEST_SEQUENCE.lock().unwrap();
+        match set_locale(LocaleCategory::Time, &locale("xx_XX.UTF-8")) {
+            Err(LocaleError::OSError(reason)) => assert!(reason.contains("LC_TIME")),
+            result => panic!("expected an OS error, not {:?}", result),
+        }
+        assert_eq!(
+            current_locale(LocaleCategory::Time).unwrap(),
+            Locale::posix()
+        );
+    }
+
+    fn thread_handle() -> libc::locale_t {
+        unsafe { libc::uselocale(ptr::null_mut()) }
+    }
+
+    #[test]
+    fn test_with_active() {
+        let outer = ThreadLocale::new(LocaleCategory::All, &locale("C.UTF-8")).unwrap();
+        let inner = ThreadLocale::new(LocaleCategory::Time, &locale("C.UTF-8")).unwrap();
+        let before = thread_handle();
+        let result = outer.with_active(|| {
+            assert_eq!(thread_handle(), outer.handle);
+            inner
+                .with_active(|| assert_eq!(thread_handle(), inner.handle))
+                .unwrap();
+            assert_eq!(thread_handle(), outer.handle);
+            "done"
+        });
+        assert_eq!(result, Ok("done"));
+        assert_eq!(thread_handle(), before);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            outer.with_active(|| panic!("unwinding through with_active"))
+        }));
+        assert!(result.is_err());
+        assert_eq!(thread_handle(), before);
+    }
+
+    #[cfg(target_env = "gnu")]
+    #[test]
+    fn test_thread_locale() {
+        let _lock = TEST_SEQUENCE.lock().unwrap();
+        let thread_locale = ThreadLocale::new(LocaleCategory::Messages, &locale("C.UTF-8"))
+            .unwrap()
+            .with(LocaleCategory::Numeric, &locale("C.UTF-8"))
+            .unwrap();
+        assert_eq!(
+            thread_locale.locale(LocaleCategory::Numeric).unwrap(),
+            locale("C.UTF-8")
+        );
+        assert_eq!(
+            thread_locale.locale(LocaleCategory::Time).unwrap(),
+            Locale::posix()
+        );
+        thread_locale
+            .with_active(|| {
+                assert_eq!(
+                    current_thread_locale(LocaleCategory::Messages).unwrap(),
+                    locale("C.UTF-8")
+                );
+                assert_eq!(
+                    current_locale(LocaleCategory::Messages).unwrap(),
+                    Locale::posix()
+                );
+            })
+            .unwrap();
+        assert_eq!(
+            current_thread_locale(LocaleCategory::Messages).unwrap(),
+            Locale::posix()
+        );
+    }
+
+    #[test]
+    fn test_thread_locale_unknown() {
+        match ThreadLocale::new(LocaleCategory::All, &locale("xx_XX")) {
+            Err(LocaleError::OSError(reason)) => assert!(reason.starts_with("newlocale(LC_ALL")),
+            result => panic!("expected an OS error, not {:?}", result),
+        }
+        assert!(ThreadLocale::new(LocaleCategory::All, &Locale::posix())
+            .unwrap()
+            .with(LocaleCategory::Time, &locale("xx_XX"))
+            .is_err());
+    }
+}
@@ -0,0 +1,233 @@
+/*!
+Provides a registry of IANA character set names, enabled by the `charsets` feature.
+
+The registry holds every character set in the IANA Character Sets registry; each
+entry records the IANA name, the preferred MIME name, the MIBenum and all registered
+aliases. Names are compared using the _charset alias matching_
+rules of [Unicode Technical Standard #22](https://www.unicode.org/reports/tr22/#Charset_Alias_Matching),
+that is ignoring case and any characters other than letters and digits, so that
+`utf8`, `UTF8`, `utf-8` and `UTF-8` all resolve to the same entry.
+
+## Examples
+
+```
+use locale_types::registry::charsets::{are_equivalent, lookup};
+
+let utf_8 = lookup("utf8").unwrap();
+assert_eq!(utf_8.name(), "UTF-8");
+assert_eq!(utf_8.mib_enum(), 106);
+
+let latin_1 = lookup("latin1").unwrap();
+assert_eq!(latin_1.name(), "ISO_8859-1:1987");
+assert_eq!(latin_1.preferred_name(), "ISO-8859-1");
+
+assert!(are_equivalent("ISO8859-1", "latin1"));
+assert!(!are_equivalent("ISO-8859-1", "ISO-8859-15"));
+```
+
+```
+use locale_types::LocaleString;
+use std::str::FromStr;
+
+let first = LocaleString::from_str("en_US.utf8").unwrap();
+let second = LocaleString::from_str("en_US.UTF-8").unwrap();
+assert_eq!(first.charset().unwrap().preferred_name(), "UTF-8");
+assert!(first.same_code_set(&second));
+```
+*/
+
+mod data;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A single entry in the character set registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Charset {
+    name: &'static str,
+    preferred_mime_name: Option<&'static str>,
+    mib_enum: u16,
+    aliases: &'static [&'static str],
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Find the registry entry for `name`, which may be the IANA name, the preferred
+/// MIME name or any alias, compared according to the UTS #22 alias matching rules.
+/// A few registered names, such as `iso-ir-9-1` and `iso-ir-91`, are only distinct
+/// by punctuation, so a name that matches exactly, ignoring case, is preferred.
+pub fn lookup(name: &str) -> Option<&'static Charset> {
+    let key = match_key(name);
+    if key.is_empty() {
+        return None;
+    }
+    data::CHARSETS
+        .iter()
+        .find(|charset| charset.names().any(|n| n.eq_ignore_ascii_case(name)))
+        .or_else(|| {
+            data::CHARSETS
+                .iter()
+                .find(|charset| charset.names().any(|n| match_key(n) == key))
+        })
+}
+
+/// Find the registry entry with the given MIBenum.
+pub fn lookup_mib_enum(mib_enum: u16) -> Option<&'static Charset> {
+    data::CHARSETS
+        .binary_search_by(|charset| charset.mib_enum.cmp(&mib_enum))
+        .ok()
+        .map(|index| &data::CHARSETS[index])
+}
+
+/// Returns `true` if `name` is the name, or an alias, of a registered character set.
+pub fn is_known(name: &str) -> bool {
+    lookup(name).is_some()
+}
+
+/// Return the preferred name for the character set named `name`, if it is known.
+pub fn canonical_name(name: &str) -> Option<&'static str> {
+    lookup(name).map(|charset| charset.preferred_name())
+}
+
+/// Returns `true` if `lhs` and `rhs` name the same character set; two unknown names
+/// are equivalent only if they are equal according to the UTS #22 matching rules.
+pub fn are_equivalent(lhs: &str, rhs: &str) -> bool {
+    match (lookup(lhs), lookup(rhs)) {
+        (Some(lhs), Some(rhs)) => lhs.mib_enum == rhs.mib_enum,
+        (None, None) => match_key(lhs) == match_key(rhs),
+        _ => false,
+    }
+}
+
+/// Return an iterator over all entries in the registry, ordered by MIBenum.
+pub fn all() -> impl Iterator<Item = &'static Charset> {
+    data::CHARSETS.iter()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Charset {
+    /// Return the IANA registered name, such as `ISO_8859-1:1987`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return the preferred MIME name, such as `ISO-8859-1`, if one is registered.
+    pub fn preferred_mime_name(&self) -> Option<&'static str> {
+        self.preferred_mime_name
+    }
+
+    /// Return the preferred MIME name if one is registered, otherwise the IANA
+    /// name; this is the form that should be used in locale identifiers.
+    pub fn preferred_name(&self) -> &'static str {
+        self.preferred_mime_name.unwrap_or(self.name)
+    }
+
+    /// Return the MIBenum, the unique numeric identifier of this character set.
+    pub fn mib_enum(&self) -> u16 {
+        self.mib_enum
+    }
+
+    /// Return the registered aliases, not including the name itself.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    fn names(&self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.name).chain(self.aliases.iter().copied())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+/// The UTS #22 matching key; any zero that is not preceded by a digit but is followed
+/// by one is removed, then only ASCII letters and digits are kept, in lower-case.
+fn match_key(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let is_digit = |index: Option<usize>| {
+        index
+            .and_then(|index| chars.get(index))
+            .is_some_and(|c| c.is_ascii_digit())
+    };
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(index, c)| {
+            c.is_ascii_alphanumeric()
+                && !(**c == '0' && !is_digit(index.checked_sub(1)) && is_digit(Some(index + 1)))
+        })
+        .map(|(_, c)| c.to_ascii_lowercase())
+        .collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_are_unambiguous() {
+        for charset in all() {
+            for name in charset.names() {
+                assert_eq!(lookup(name), Some(charset), "ambiguous name {}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_match_key() {
+        assert_eq!(match_key("UTF-8"), "utf8");
+        assert_eq!(match_key("ISO_8859-1:1987"), "iso885911987");
+        assert_eq!(match_key("ISO-8859-01"), "iso88591");
+        assert_eq!(match_key("windows-1250"), "windows1250");
+        assert_eq!(match_key("-_."), "");
+    }
+
+    #[test]
+    fn test_lookup() {
+        for name in &["UTF-8", "utf-8", "UTF8", "utf8", "csUTF8"] {
+            assert_eq!(lookup(name).unwrap().mib_enum(), 106);
+        }
+        assert_eq!(lookup("ISO8859-15").unwrap().mib_enum(), 111);
+        assert_eq!(lookup("eucJP").unwrap().preferred_name(), "EUC-JP");
+        assert_eq!(lookup("ANSI_X3.4-1968").unwrap().name(), "US-ASCII");
+        assert_eq!(lookup("IBM037").unwrap().mib_enum(), 2028);
+        assert_eq!(lookup("IBM00858").unwrap().name(), "IBM00858");
+        assert!(lookup("ARMSCII-8").is_none());
+        assert!(lookup("").is_none());
+        assert_eq!(lookup("iso-ir-9-1").unwrap().name(), "NATS-DANO");
+        assert_eq!(lookup("iso-ir-91").unwrap().name(), "JIS_C6229-1984-a");
+    }
+
+    #[test]
+    fn test_lookup_mib_enum() {
+        assert_eq!(lookup_mib_enum(2026).unwrap().name(), "Big5");
+        assert_eq!(lookup_mib_enum(2028).unwrap().name(), "IBM037");
+        assert_eq!(lookup_mib_enum(2252).unwrap().name(), "windows-1252");
+        assert!(lookup_mib_enum(1).is_none());
+    }
+
+    #[test]
+    fn test_canonical_and_equivalent() {
+        assert_eq!(canonical_name("latin-9"), Some("ISO-8859-15"));
+        assert_eq!(canonical_name("cswindows1252"), Some("windows-1252"));
+        assert_eq!(canonical_name("ebcdic-cp-us"), Some("IBM037"));
+        assert_eq!(canonical_name("cp037"), Some("IBM037"));
+        assert_eq!(canonical_name("csIBM037"), Some("IBM037"));
+        assert_eq!(canonical_name("cp862"), Some("IBM862"));
+        assert_eq!(canonical_name("ISO_646.irv:1983"), Some("ISO_646.irv:1983"));
+        assert!(are_equivalent("utf8", "UTF-8"));
+        assert!(are_equivalent("SJIS-X", "sjis_x"));
+        assert!(!are_equivalent("UTF-8", "UTF-16"));
+    }
+}
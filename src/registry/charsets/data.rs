@@ -0,0 +1,265 @@
+// Generated from the IANA Character Sets registry; ordered by MIBenum.
+
+use super::Charset;
+
+#[rustfmt::skip]
+pub(super) static CHARSETS: [Charset; 258] = [
+    Charset { name: "US-ASCII", preferred_mime_name: Some("US-ASCII"), mib_enum: 3, aliases: &["iso-ir-6", "ANSI_X3.4-1968", "ANSI_X3.4-1986", "ISO_646.irv:1991", "ISO646-US", "us", "IBM367", "cp367", "csASCII"] },
+    Charset { name: "ISO_8859-1:1987", preferred_mime_name: Some("ISO-8859-1"), mib_enum: 4, aliases: &["iso-ir-100", "ISO_8859-1", "ISO-8859-1", "latin1", "l1", "IBM819", "CP819", "csISOLatin1"] },
+    Charset { name: "ISO_8859-2:1987", preferred_mime_name: Some("ISO-8859-2"), mib_enum: 5, aliases: &["iso-ir-101", "ISO_8859-2", "ISO-8859-2", "latin2", "l2", "csISOLatin2"] },
+    Charset { name: "ISO_8859-3:1988", preferred_mime_name: Some("ISO-8859-3"), mib_enum: 6, aliases: &["iso-ir-109", "ISO_8859-3", "ISO-8859-3", "latin3", "l3", "csISOLatin3"] },
+    Charset { name: "ISO_8859-4:1988", preferred_mime_name: Some("ISO-8859-4"), mib_enum: 7, aliases: &["iso-ir-110", "ISO_8859-4", "ISO-8859-4", "latin4", "l4", "csISOLatin4"] },
+    Charset { name: "ISO_8859-5:1988", preferred_mime_name: Some("ISO-8859-5"), mib_enum: 8, aliases: &["iso-ir-144", "ISO_8859-5", "ISO-8859-5", "cyrillic", "csISOLatinCyrillic"] },
+    Charset { name: "ISO_8859-6:1987", preferred_mime_name: Some("ISO-8859-6"), mib_enum: 9, aliases: &["iso-ir-127", "ISO_8859-6", "ISO-8859-6", "ECMA-114", "ASMO-708", "arabic", "csISOLatinArabic"] },
+    Charset { name: "ISO_8859-7:1987", preferred_mime_name: Some("ISO-8859-7"), mib_enum: 10, aliases: &["iso-ir-126", "ISO_8859-7", "ISO-8859-7", "ELOT_928", "ECMA-118", "greek", "greek8", "csISOLatinGreek"] },
+    Charset { name: "ISO_8859-8:1988", preferred_mime_name: Some("ISO-8859-8"), mib_enum: 11, aliases: &["iso-ir-138", "ISO_8859-8", "ISO-8859-8", "hebrew", "csISOLatinHebrew"] },
+    Charset { name: "ISO_8859-9:1989", preferred_mime_name: Some("ISO-8859-9"), mib_enum: 12, aliases: &["iso-ir-148", "ISO_8859-9", "ISO-8859-9", "latin5", "l5", "csISOLatin5"] },
+    Charset { name: "ISO-8859-10", preferred_mime_name: Some("ISO-8859-10"), mib_enum: 13, aliases: &["iso-ir-157", "l6", "ISO_8859-10:1992", "csISOLatin6", "latin6"] },
+    Charset { name: "ISO_6937-2-add", preferred_mime_name: None, mib_enum: 14, aliases: &["iso-ir-142", "csISOTextComm"] },
+    Charset { name: "JIS_X0201", preferred_mime_name: None, mib_enum: 15, aliases: &["X0201", "csHalfWidthKatakana"] },
+    Charset { name: "JIS_Encoding", preferred_mime_name: None, mib_enum: 16, aliases: &["csJISEncoding"] },
+    Charset { name: "Shift_JIS", preferred_mime_name: Some("Shift_JIS"), mib_enum: 17, aliases: &["MS_Kanji", "csShiftJIS"] },
+    Charset { name: "Extended_UNIX_Code_Packed_Format_for_Japanese", preferred_mime_name: Some("EUC-JP"), mib_enum: 18, aliases: &["csEUCPkdFmtJapanese", "EUC-JP"] },
+    Charset { name: "Extended_UNIX_Code_Fixed_Width_for_Japanese", preferred_mime_name: None, mib_enum: 19, aliases: &["csEUCFixWidJapanese"] },
+    Charset { name: "BS_4730", preferred_mime_name: None, mib_enum: 20, aliases: &["iso-ir-4", "ISO646-GB", "gb", "uk", "csISO4UnitedKingdom"] },
+    Charset { name: "SEN_850200_C", preferred_mime_name: None, mib_enum: 21, aliases: &["iso-ir-11", "ISO646-SE2", "se2", "csISO11SwedishForNames"] },
+    Charset { name: "IT", preferred_mime_name: None, mib_enum: 22, aliases: &["iso-ir-15", "ISO646-IT", "csISO15Italian"] },
+    Charset { name: "ES", preferred_mime_name: None, mib_enum: 23, aliases: &["iso-ir-17", "ISO646-ES", "csISO17Spanish"] },
+    Charset { name: "DIN_66003", preferred_mime_name: None, mib_enum: 24, aliases: &["iso-ir-21", "de", "ISO646-DE", "csISO21German"] },
+    Charset { name: "NS_4551-1", preferred_mime_name: None, mib_enum: 25, aliases: &["iso-ir-60", "ISO646-NO", "no", "csISO60DanishNorwegian", "csISO60Norwegian1"] },
+    Charset { name: "NF_Z_62-010", preferred_mime_name: None, mib_enum: 26, aliases: &["iso-ir-69", "ISO646-FR", "fr", "csISO69French"] },
+    Charset { name: "ISO-10646-UTF-1", preferred_mime_name: None, mib_enum: 27, aliases: &["csISO10646UTF1"] },
+    Charset { name: "ISO_646.basic:1983", preferred_mime_name: None, mib_enum: 28, aliases: &["ref", "csISO646basic1983"] },
+    Charset { name: "INVARIANT", preferred_mime_name: None, mib_enum: 29, aliases: &["csINVARIANT"] },
+    Charset { name: "ISO_646.irv:1983", preferred_mime_name: None, mib_enum: 30, aliases: &["iso-ir-2", "irv", "csISO2IntlRefVersion"] },
+    Charset { name: "NATS-SEFI", preferred_mime_name: None, mib_enum: 31, aliases: &["iso-ir-8-1", "csNATSSEFI"] },
+    Charset { name: "NATS-SEFI-ADD", preferred_mime_name: None, mib_enum: 32, aliases: &["iso-ir-8-2", "csNATSSEFIADD"] },
+    Charset { name: "NATS-DANO", preferred_mime_name: None, mib_enum: 33, aliases: &["iso-ir-9-1", "csNATSDANO"] },
+    Charset { name: "NATS-DANO-ADD", preferred_mime_name: None, mib_enum: 34, aliases: &["iso-ir-9-2", "csNATSDANOADD"] },
+    Charset { name: "SEN_850200_B", preferred_mime_name: None, mib_enum: 35, aliases: &["iso-ir-10", "FI", "ISO646-FI", "ISO646-SE", "se", "csISO10Swedish"] },
+    Charset { name: "KS_C_5601-1987", preferred_mime_name: None, mib_enum: 36, aliases: &["iso-ir-149", "KS_C_5601-1989", "KSC_5601", "korean", "csKSC56011987"] },
+    Charset { name: "ISO-2022-KR", preferred_mime_name: Some("ISO-2022-KR"), mib_enum: 37, aliases: &["csISO2022KR"] },
+    Charset { name: "EUC-KR", preferred_mime_name: Some("EUC-KR"), mib_enum: 38, aliases: &["csEUCKR"] },
+    Charset { name: "ISO-2022-JP", preferred_mime_name: Some("ISO-2022-JP"), mib_enum: 39, aliases: &["csISO2022JP"] },
+    Charset { name: "ISO-2022-JP-2", preferred_mime_name: Some("ISO-2022-JP-2"), mib_enum: 40, aliases: &["csISO2022JP2"] },
+    Charset { name: "JIS_C6220-1969-jp", preferred_mime_name: None, mib_enum: 41, aliases: &["JIS_C6220-1969", "iso-ir-13", "katakana", "x0201-7", "csISO13JISC6220jp"] },
+    Charset { name: "JIS_C6220-1969-ro", preferred_mime_name: None, mib_enum: 42, aliases: &["iso-ir-14", "jp", "ISO646-JP", "csISO14JISC6220ro"] },
+    Charset { name: "PT", preferred_mime_name: None, mib_enum: 43, aliases: &["iso-ir-16", "ISO646-PT", "csISO16Portuguese"] },
+    Charset { name: "greek7-old", preferred_mime_name: None, mib_enum: 44, aliases: &["iso-ir-18", "csISO18Greek7Old"] },
+    Charset { name: "latin-greek", preferred_mime_name: None, mib_enum: 45, aliases: &["iso-ir-19", "csISO19LatinGreek"] },
+    Charset { name: "NF_Z_62-010_(1973)", preferred_mime_name: None, mib_enum: 46, aliases: &["iso-ir-25", "ISO646-FR1", "csISO25French"] },
+    Charset { name: "Latin-greek-1", preferred_mime_name: None, mib_enum: 47, aliases: &["iso-ir-27", "csISO27LatinGreek1"] },
+    Charset { name: "ISO_5427", preferred_mime_name: None, mib_enum: 48, aliases: &["iso-ir-37", "csISO5427Cyrillic"] },
+    Charset { name: "JIS_C6226-1978", preferred_mime_name: None, mib_enum: 49, aliases: &["iso-ir-42", "csISO42JISC62261978"] },
+    Charset { name: "BS_viewdata", preferred_mime_name: None, mib_enum: 50, aliases: &["iso-ir-47", "csISO47BSViewdata"] },
+    Charset { name: "INIS", preferred_mime_name: None, mib_enum: 51, aliases: &["iso-ir-49", "csISO49INIS"] },
+    Charset { name: "INIS-8", preferred_mime_name: None, mib_enum: 52, aliases: &["iso-ir-50", "csISO50INIS8"] },
+    Charset { name: "INIS-cyrillic", preferred_mime_name: None, mib_enum: 53, aliases: &["iso-ir-51", "csISO51INISCyrillic"] },
+    Charset { name: "ISO_5427:1981", preferred_mime_name: None, mib_enum: 54, aliases: &["iso-ir-54", "ISO5427Cyrillic1981", "csISO54271981"] },
+    Charset { name: "ISO_5428:1980", preferred_mime_name: None, mib_enum: 55, aliases: &["iso-ir-55", "csISO5428Greek"] },
+    Charset { name: "GB_1988-80", preferred_mime_name: None, mib_enum: 56, aliases: &["iso-ir-57", "cn", "ISO646-CN", "csISO57GB1988"] },
+    Charset { name: "GB_2312-80", preferred_mime_name: None, mib_enum: 57, aliases: &["iso-ir-58", "chinese", "csISO58GB231280"] },
+    Charset { name: "NS_4551-2", preferred_mime_name: None, mib_enum: 58, aliases: &["ISO646-NO2", "iso-ir-61", "no2", "csISO61Norwegian2"] },
+    Charset { name: "videotex-suppl", preferred_mime_name: None, mib_enum: 59, aliases: &["iso-ir-70", "csISO70VideotexSupp1"] },
+    Charset { name: "PT2", preferred_mime_name: None, mib_enum: 60, aliases: &["iso-ir-84", "ISO646-PT2", "csISO84Portuguese2"] },
+    Charset { name: "ES2", preferred_mime_name: None, mib_enum: 61, aliases: &["iso-ir-85", "ISO646-ES2", "csISO85Spanish2"] },
+    Charset { name: "MSZ_7795.3", preferred_mime_name: None, mib_enum: 62, aliases: &["iso-ir-86", "ISO646-HU", "hu", "csISO86Hungarian"] },
+    Charset { name: "JIS_C6226-1983", preferred_mime_name: None, mib_enum: 63, aliases: &["iso-ir-87", "x0208", "JIS_X0208-1983", "csISO87JISX0208"] },
+    Charset { name: "greek7", preferred_mime_name: None, mib_enum: 64, aliases: &["iso-ir-88", "csISO88Greek7"] },
+    Charset { name: "ASMO_449", preferred_mime_name: None, mib_enum: 65, aliases: &["ISO_9036", "arabic7", "iso-ir-89", "csISO89ASMO449"] },
+    Charset { name: "iso-ir-90", preferred_mime_name: None, mib_enum: 66, aliases: &["csISO90"] },
+    Charset { name: "JIS_C6229-1984-a", preferred_mime_name: None, mib_enum: 67, aliases: &["iso-ir-91", "jp-ocr-a", "csISO91JISC62291984a"] },
+    Charset { name: "JIS_C6229-1984-b", preferred_mime_name: None, mib_enum: 68, aliases: &["iso-ir-92", "ISO646-JP-OCR-B", "jp-ocr-b", "csISO92JISC62991984b"] },
+    Charset { name: "JIS_C6229-1984-b-add", preferred_mime_name: None, mib_enum: 69, aliases: &["iso-ir-93", "jp-ocr-b-add", "csISO93JIS62291984badd"] },
+    Charset { name: "JIS_C6229-1984-hand", preferred_mime_name: None, mib_enum: 70, aliases: &["iso-ir-94", "jp-ocr-hand", "csISO94JIS62291984hand"] },
+    Charset { name: "JIS_C6229-1984-hand-add", preferred_mime_name: None, mib_enum: 71, aliases: &["iso-ir-95", "jp-ocr-hand-add", "csISO95JIS62291984handadd"] },
+    Charset { name: "JIS_C6229-1984-kana", preferred_mime_name: None, mib_enum: 72, aliases: &["iso-ir-96", "csISO96JISC62291984kana"] },
+    Charset { name: "ISO_2033-1983", preferred_mime_name: None, mib_enum: 73, aliases: &["iso-ir-98", "e13b", "csISO2033"] },
+    Charset { name: "ANSI_X3.110-1983", preferred_mime_name: None, mib_enum: 74, aliases: &["iso-ir-99", "CSA_T500-1983", "NAPLPS", "csISO99NAPLPS"] },
+    Charset { name: "T.61-7bit", preferred_mime_name: None, mib_enum: 75, aliases: &["iso-ir-102", "csISO102T617bit"] },
+    Charset { name: "T.61-8bit", preferred_mime_name: None, mib_enum: 76, aliases: &["T.61", "iso-ir-103", "csISO103T618bit"] },
+    Charset { name: "ECMA-cyrillic", preferred_mime_name: None, mib_enum: 77, aliases: &["iso-ir-111", "KOI8-E", "csISO111ECMACyrillic"] },
+    Charset { name: "CSA_Z243.4-1985-1", preferred_mime_name: None, mib_enum: 78, aliases: &["iso-ir-121", "ISO646-CA", "csa7-1", "csa71", "ca", "csISO121Canadian1"] },
+    Charset { name: "CSA_Z243.4-1985-2", preferred_mime_name: None, mib_enum: 79, aliases: &["iso-ir-122", "ISO646-CA2", "csa7-2", "csa72", "csISO122Canadian2"] },
+    Charset { name: "CSA_Z243.4-1985-gr", preferred_mime_name: None, mib_enum: 80, aliases: &["iso-ir-123", "csISO123CSAZ24341985gr"] },
+    Charset { name: "ISO_8859-6-E", preferred_mime_name: Some("ISO-8859-6-E"), mib_enum: 81, aliases: &["csISO88596E", "ISO-8859-6-E"] },
+    Charset { name: "ISO_8859-6-I", preferred_mime_name: Some("ISO-8859-6-I"), mib_enum: 82, aliases: &["csISO88596I", "ISO-8859-6-I"] },
+    Charset { name: "T.101-G2", preferred_mime_name: None, mib_enum: 83, aliases: &["iso-ir-128", "csISO128T101G2"] },
+    Charset { name: "ISO_8859-8-E", preferred_mime_name: Some("ISO-8859-8-E"), mib_enum: 84, aliases: &["csISO88598E", "ISO-8859-8-E"] },
+    Charset { name: "ISO_8859-8-I", preferred_mime_name: Some("ISO-8859-8-I"), mib_enum: 85, aliases: &["csISO88598I", "ISO-8859-8-I"] },
+    Charset { name: "CSN_369103", preferred_mime_name: None, mib_enum: 86, aliases: &["iso-ir-139", "csISO139CSN369103"] },
+    Charset { name: "JUS_I.B1.002", preferred_mime_name: None, mib_enum: 87, aliases: &["iso-ir-141", "ISO646-YU", "js", "yu", "csISO141JUSIB1002"] },
+    Charset { name: "IEC_P27-1", preferred_mime_name: None, mib_enum: 88, aliases: &["iso-ir-143", "csISO143IECP271"] },
+    Charset { name: "JUS_I.B1.003-serb", preferred_mime_name: None, mib_enum: 89, aliases: &["iso-ir-146", "serbian", "csISO146Serbian"] },
+    Charset { name: "JUS_I.B1.003-mac", preferred_mime_name: None, mib_enum: 90, aliases: &["macedonian", "iso-ir-147", "csISO147Macedonian"] },
+    Charset { name: "greek-ccitt", preferred_mime_name: None, mib_enum: 91, aliases: &["iso-ir-150", "csISO150", "csISO150GreekCCITT"] },
+    Charset { name: "NC_NC00-10:81", preferred_mime_name: None, mib_enum: 92, aliases: &["cuba", "iso-ir-151", "ISO646-CU", "csISO151Cuba"] },
+    Charset { name: "ISO_6937-2-25", preferred_mime_name: None, mib_enum: 93, aliases: &["iso-ir-152", "csISO6937Add"] },
+    Charset { name: "GOST_19768-74", preferred_mime_name: None, mib_enum: 94, aliases: &["ST_SEV_358-88", "iso-ir-153", "csISO153GOST1976874"] },
+    Charset { name: "ISO_8859-supp", preferred_mime_name: None, mib_enum: 95, aliases: &["iso-ir-154", "latin1-2-5", "csISO8859Supp"] },
+    Charset { name: "ISO_10367-box", preferred_mime_name: None, mib_enum: 96, aliases: &["iso-ir-155", "csISO10367Box"] },
+    Charset { name: "latin-lap", preferred_mime_name: None, mib_enum: 97, aliases: &["lap", "iso-ir-158", "csISO158Lap"] },
+    Charset { name: "JIS_X0212-1990", preferred_mime_name: None, mib_enum: 98, aliases: &["x0212", "iso-ir-159", "csISO159JISX02121990"] },
+    Charset { name: "DS_2089", preferred_mime_name: None, mib_enum: 99, aliases: &["DS2089", "ISO646-DK", "dk", "csISO646Danish"] },
+    Charset { name: "us-dk", preferred_mime_name: None, mib_enum: 100, aliases: &["csUSDK"] },
+    Charset { name: "dk-us", preferred_mime_name: None, mib_enum: 101, aliases: &["csDKUS"] },
+    Charset { name: "KSC5636", preferred_mime_name: None, mib_enum: 102, aliases: &["ISO646-KR", "csKSC5636"] },
+    Charset { name: "UNICODE-1-1-UTF-7", preferred_mime_name: None, mib_enum: 103, aliases: &["csUnicode11UTF7"] },
+    Charset { name: "ISO-2022-CN", preferred_mime_name: None, mib_enum: 104, aliases: &["csISO2022CN"] },
+    Charset { name: "ISO-2022-CN-EXT", preferred_mime_name: None, mib_enum: 105, aliases: &["csISO2022CNEXT"] },
+    Charset { name: "UTF-8", preferred_mime_name: None, mib_enum: 106, aliases: &["csUTF8"] },
+    Charset { name: "ISO-8859-13", preferred_mime_name: None, mib_enum: 109, aliases: &["csISO885913"] },
+    Charset { name: "ISO-8859-14", preferred_mime_name: None, mib_enum: 110, aliases: &["iso-ir-199", "ISO_8859-14:1998", "ISO_8859-14", "latin8", "iso-celtic", "l8", "csISO885914"] },
+    Charset { name: "ISO-8859-15", preferred_mime_name: None, mib_enum: 111, aliases: &["ISO_8859-15", "Latin-9", "csISO885915"] },
+    Charset { name: "ISO-8859-16", preferred_mime_name: None, mib_enum: 112, aliases: &["iso-ir-226", "ISO_8859-16:2001", "ISO_8859-16", "latin10", "l10", "csISO885916"] },
+    Charset { name: "GBK", preferred_mime_name: None, mib_enum: 113, aliases: &["CP936", "MS936", "windows-936", "csGBK"] },
+    Charset { name: "GB18030", preferred_mime_name: None, mib_enum: 114, aliases: &["csGB18030"] },
+    Charset { name: "OSD_EBCDIC_DF04_15", preferred_mime_name: None, mib_enum: 115, aliases: &["csOSDEBCDICDF0415"] },
+    Charset { name: "OSD_EBCDIC_DF03_IRV", preferred_mime_name: None, mib_enum: 116, aliases: &["csOSDEBCDICDF03IRV"] },
+    Charset { name: "OSD_EBCDIC_DF04_1", preferred_mime_name: None, mib_enum: 117, aliases: &["csOSDEBCDICDF041"] },
+    Charset { name: "ISO-11548-1", preferred_mime_name: None, mib_enum: 118, aliases: &["ISO_11548-1", "ISO_TR_11548-1", "csISO115481"] },
+    Charset { name: "KZ-1048", preferred_mime_name: None, mib_enum: 119, aliases: &["STRK1048-2002", "RK1048", "csKZ1048"] },
+    Charset { name: "ISO-10646-UCS-2", preferred_mime_name: None, mib_enum: 1000, aliases: &["csUnicode"] },
+    Charset { name: "ISO-10646-UCS-4", preferred_mime_name: None, mib_enum: 1001, aliases: &["csUCS4"] },
+    Charset { name: "ISO-10646-UCS-Basic", preferred_mime_name: None, mib_enum: 1002, aliases: &["csUnicodeASCII"] },
+    Charset { name: "ISO-10646-Unicode-Latin1", preferred_mime_name: None, mib_enum: 1003, aliases: &["csUnicodeLatin1", "ISO-10646"] },
+    Charset { name: "ISO-10646-J-1", preferred_mime_name: None, mib_enum: 1004, aliases: &["csUnicodeJapanese"] },
+    Charset { name: "ISO-Unicode-IBM-1261", preferred_mime_name: None, mib_enum: 1005, aliases: &["csUnicodeIBM1261"] },
+    Charset { name: "ISO-Unicode-IBM-1268", preferred_mime_name: None, mib_enum: 1006, aliases: &["csUnicodeIBM1268"] },
+    Charset { name: "ISO-Unicode-IBM-1276", preferred_mime_name: None, mib_enum: 1007, aliases: &["csUnicodeIBM1276"] },
+    Charset { name: "ISO-Unicode-IBM-1264", preferred_mime_name: None, mib_enum: 1008, aliases: &["csUnicodeIBM1264"] },
+    Charset { name: "ISO-Unicode-IBM-1265", preferred_mime_name: None, mib_enum: 1009, aliases: &["csUnicodeIBM1265"] },
+    Charset { name: "UNICODE-1-1", preferred_mime_name: None, mib_enum: 1010, aliases: &["csUnicode11"] },
+    Charset { name: "SCSU", preferred_mime_name: None, mib_enum: 1011, aliases: &["csSCSU"] },
+    Charset { name: "UTF-7", preferred_mime_name: None, mib_enum: 1012, aliases: &["csUTF7"] },
+    Charset { name: "UTF-16BE", preferred_mime_name: None, mib_enum: 1013, aliases: &["csUTF16BE"] },
+    Charset { name: "UTF-16LE", preferred_mime_name: None, mib_enum: 1014, aliases: &["csUTF16LE"] },
+    Charset { name: "UTF-16", preferred_mime_name: None, mib_enum: 1015, aliases: &["csUTF16"] },
+    Charset { name: "CESU-8", preferred_mime_name: None, mib_enum: 1016, aliases: &["csCESU8", "csCESU-8"] },
+    Charset { name: "UTF-32", preferred_mime_name: None, mib_enum: 1017, aliases: &["csUTF32"] },
+    Charset { name: "UTF-32BE", preferred_mime_name: None, mib_enum: 1018, aliases: &["csUTF32BE"] },
+    Charset { name: "UTF-32LE", preferred_mime_name: None, mib_enum: 1019, aliases: &["csUTF32LE"] },
+    Charset { name: "BOCU-1", preferred_mime_name: None, mib_enum: 1020, aliases: &["csBOCU1", "csBOCU-1"] },
+    Charset { name: "UTF-7-IMAP", preferred_mime_name: None, mib_enum: 1021, aliases: &["csUTF7IMAP"] },
+    Charset { name: "ISO-8859-1-Windows-3.0-Latin-1", preferred_mime_name: None, mib_enum: 2000, aliases: &["csWindows30Latin1"] },
+    Charset { name: "ISO-8859-1-Windows-3.1-Latin-1", preferred_mime_name: None, mib_enum: 2001, aliases: &["csWindows31Latin1"] },
+    Charset { name: "ISO-8859-2-Windows-Latin-2", preferred_mime_name: None, mib_enum: 2002, aliases: &["csWindows31Latin2"] },
+    Charset { name: "ISO-8859-9-Windows-Latin-5", preferred_mime_name: None, mib_enum: 2003, aliases: &["csWindows31Latin5"] },
+    Charset { name: "hp-roman8", preferred_mime_name: None, mib_enum: 2004, aliases: &["roman8", "r8", "csHPRoman8"] },
+    Charset { name: "Adobe-Standard-Encoding", preferred_mime_name: None, mib_enum: 2005, aliases: &["csAdobeStandardEncoding"] },
+    Charset { name: "Ventura-US", preferred_mime_name: None, mib_enum: 2006, aliases: &["csVenturaUS"] },
+    Charset { name: "Ventura-International", preferred_mime_name: None, mib_enum: 2007, aliases: &["csVenturaInternational"] },
+    Charset { name: "DEC-MCS", preferred_mime_name: None, mib_enum: 2008, aliases: &["dec", "csDECMCS"] },
+    Charset { name: "IBM850", preferred_mime_name: None, mib_enum: 2009, aliases: &["cp850", "850", "csPC850Multilingual"] },
+    Charset { name: "IBM852", preferred_mime_name: None, mib_enum: 2010, aliases: &["cp852", "852", "csPCp852"] },
+    Charset { name: "IBM437", preferred_mime_name: None, mib_enum: 2011, aliases: &["cp437", "437", "csPC8CodePage437"] },
+    Charset { name: "PC8-Danish-Norwegian", preferred_mime_name: None, mib_enum: 2012, aliases: &["csPC8DanishNorwegian"] },
+    Charset { name: "IBM862", preferred_mime_name: None, mib_enum: 2013, aliases: &["cp862", "862", "csPC862LatinHebrew"] },
+    Charset { name: "PC8-Turkish", preferred_mime_name: None, mib_enum: 2014, aliases: &["csPC8Turkish"] },
+    Charset { name: "IBM-Symbols", preferred_mime_name: None, mib_enum: 2015, aliases: &["csIBMSymbols"] },
+    Charset { name: "IBM-Thai", preferred_mime_name: None, mib_enum: 2016, aliases: &["csIBMThai"] },
+    Charset { name: "HP-Legal", preferred_mime_name: None, mib_enum: 2017, aliases: &["csHPLegal"] },
+    Charset { name: "HP-Pi-font", preferred_mime_name: None, mib_enum: 2018, aliases: &["csHPPiFont"] },
+    Charset { name: "HP-Math8", preferred_mime_name: None, mib_enum: 2019, aliases: &["csHPMath8"] },
+    Charset { name: "Adobe-Symbol-Encoding", preferred_mime_name: None, mib_enum: 2020, aliases: &["csHPPSMath"] },
+    Charset { name: "HP-DeskTop", preferred_mime_name: None, mib_enum: 2021, aliases: &["csHPDesktop"] },
+    Charset { name: "Ventura-Math", preferred_mime_name: None, mib_enum: 2022, aliases: &["csVenturaMath"] },
+    Charset { name: "Microsoft-Publishing", preferred_mime_name: None, mib_enum: 2023, aliases: &["csMicrosoftPublishing"] },
+    Charset { name: "windows-31J", preferred_mime_name: None, mib_enum: 2024, aliases: &["csWindows31J"] },
+    Charset { name: "GB2312", preferred_mime_name: Some("GB2312"), mib_enum: 2025, aliases: &["csGB2312"] },
+    Charset { name: "Big5", preferred_mime_name: Some("Big5"), mib_enum: 2026, aliases: &["csBig5"] },
+    Charset { name: "macintosh", preferred_mime_name: None, mib_enum: 2027, aliases: &["mac", "csMacintosh"] },
+    Charset { name: "IBM037", preferred_mime_name: None, mib_enum: 2028, aliases: &["cp037", "ebcdic-cp-us", "ebcdic-cp-ca", "ebcdic-cp-wt", "ebcdic-cp-nl", "csIBM037"] },
+    Charset { name: "IBM038", preferred_mime_name: None, mib_enum: 2029, aliases: &["EBCDIC-INT", "cp038", "csIBM038"] },
+    Charset { name: "IBM273", preferred_mime_name: None, mib_enum: 2030, aliases: &["CP273", "csIBM273"] },
+    Charset { name: "IBM274", preferred_mime_name: None, mib_enum: 2031, aliases: &["EBCDIC-BE", "CP274", "csIBM274"] },
+    Charset { name: "IBM275", preferred_mime_name: None, mib_enum: 2032, aliases: &["EBCDIC-BR", "cp275", "csIBM275"] },
+    Charset { name: "IBM277", preferred_mime_name: None, mib_enum: 2033, aliases: &["EBCDIC-CP-DK", "EBCDIC-CP-NO", "csIBM277"] },
+    Charset { name: "IBM278", preferred_mime_name: None, mib_enum: 2034, aliases: &["CP278", "ebcdic-cp-fi", "ebcdic-cp-se", "csIBM278"] },
+    Charset { name: "IBM280", preferred_mime_name: None, mib_enum: 2035, aliases: &["CP280", "ebcdic-cp-it", "csIBM280"] },
+    Charset { name: "IBM281", preferred_mime_name: None, mib_enum: 2036, aliases: &["EBCDIC-JP-E", "cp281", "csIBM281"] },
+    Charset { name: "IBM284", preferred_mime_name: None, mib_enum: 2037, aliases: &["CP284", "ebcdic-cp-es", "csIBM284"] },
+    Charset { name: "IBM285", preferred_mime_name: None, mib_enum: 2038, aliases: &["CP285", "ebcdic-cp-gb", "csIBM285"] },
+    Charset { name: "IBM290", preferred_mime_name: None, mib_enum: 2039, aliases: &["cp290", "EBCDIC-JP-kana", "csIBM290"] },
+    Charset { name: "IBM297", preferred_mime_name: None, mib_enum: 2040, aliases: &["cp297", "ebcdic-cp-fr", "csIBM297"] },
+    Charset { name: "IBM420", preferred_mime_name: None, mib_enum: 2041, aliases: &["cp420", "ebcdic-cp-ar1", "csIBM420"] },
+    Charset { name: "IBM423", preferred_mime_name: None, mib_enum: 2042, aliases: &["cp423", "ebcdic-cp-gr", "csIBM423"] },
+    Charset { name: "IBM424", preferred_mime_name: None, mib_enum: 2043, aliases: &["cp424", "ebcdic-cp-he", "csIBM424"] },
+    Charset { name: "IBM500", preferred_mime_name: None, mib_enum: 2044, aliases: &["CP500", "ebcdic-cp-be", "ebcdic-cp-ch", "csIBM500"] },
+    Charset { name: "IBM851", preferred_mime_name: None, mib_enum: 2045, aliases: &["cp851", "851", "csPC851"] },
+    Charset { name: "IBM855", preferred_mime_name: None, mib_enum: 2046, aliases: &["cp855", "855", "csIBM855"] },
+    Charset { name: "IBM857", preferred_mime_name: None, mib_enum: 2047, aliases: &["cp857", "857", "csIBM857"] },
+    Charset { name: "IBM860", preferred_mime_name: None, mib_enum: 2048, aliases: &["cp860", "860", "csIBM860"] },
+    Charset { name: "IBM861", preferred_mime_name: None, mib_enum: 2049, aliases: &["cp861", "861", "cp-is", "csIBM861"] },
+    Charset { name: "IBM863", preferred_mime_name: None, mib_enum: 2050, aliases: &["cp863", "863", "csIBM863"] },
+    Charset { name: "IBM864", preferred_mime_name: None, mib_enum: 2051, aliases: &["cp864", "csIBM864"] },
+    Charset { name: "IBM865", preferred_mime_name: None, mib_enum: 2052, aliases: &["cp865", "865", "csIBM865"] },
+    Charset { name: "IBM868", preferred_mime_name: None, mib_enum: 2053, aliases: &["CP868", "cp-ar", "csIBM868"] },
+    Charset { name: "IBM869", preferred_mime_name: None, mib_enum: 2054, aliases: &["cp869", "869", "cp-gr", "csIBM869"] },
+    Charset { name: "IBM870", preferred_mime_name: None, mib_enum: 2055, aliases: &["CP870", "ebcdic-cp-roece", "ebcdic-cp-yu", "csIBM870"] },
+    Charset { name: "IBM871", preferred_mime_name: None, mib_enum: 2056, aliases: &["CP871", "ebcdic-cp-is", "csIBM871"] },
+    Charset { name: "IBM880", preferred_mime_name: None, mib_enum: 2057, aliases: &["cp880", "EBCDIC-Cyrillic", "csIBM880"] },
+    Charset { name: "IBM891", preferred_mime_name: None, mib_enum: 2058, aliases: &["cp891", "csIBM891"] },
+    Charset { name: "IBM903", preferred_mime_name: None, mib_enum: 2059, aliases: &["cp903", "csIBM903"] },
+    Charset { name: "IBM904", preferred_mime_name: None, mib_enum: 2060, aliases: &["cp904", "904", "csIBBM904"] },
+    Charset { name: "IBM905", preferred_mime_name: None, mib_enum: 2061, aliases: &["CP905", "ebcdic-cp-tr", "csIBM905"] },
+    Charset { name: "IBM918", preferred_mime_name: None, mib_enum: 2062, aliases: &["CP918", "ebcdic-cp-ar2", "csIBM918"] },
+    Charset { name: "IBM1026", preferred_mime_name: None, mib_enum: 2063, aliases: &["CP1026", "csIBM1026"] },
+    Charset { name: "EBCDIC-AT-DE", preferred_mime_name: None, mib_enum: 2064, aliases: &["csIBMEBCDICATDE"] },
+    Charset { name: "EBCDIC-AT-DE-A", preferred_mime_name: None, mib_enum: 2065, aliases: &["csEBCDICATDEA"] },
+    Charset { name: "EBCDIC-CA-FR", preferred_mime_name: None, mib_enum: 2066, aliases: &["csEBCDICCAFR"] },
+    Charset { name: "EBCDIC-DK-NO", preferred_mime_name: None, mib_enum: 2067, aliases: &["csEBCDICDKNO"] },
+    Charset { name: "EBCDIC-DK-NO-A", preferred_mime_name: None, mib_enum: 2068, aliases: &["csEBCDICDKNOA"] },
+    Charset { name: "EBCDIC-FI-SE", preferred_mime_name: None, mib_enum: 2069, aliases: &["csEBCDICFISE"] },
+    Charset { name: "EBCDIC-FI-SE-A", preferred_mime_name: None, mib_enum: 2070, aliases: &["csEBCDICFISEA"] },
+    Charset { name: "EBCDIC-FR", preferred_mime_name: None, mib_enum: 2071, aliases: &["csEBCDICFR"] },
+    Charset { name: "EBCDIC-IT", preferred_mime_name: None, mib_enum: 2072, aliases: &["csEBCDICIT"] },
+    Charset { name: "EBCDIC-PT", preferred_mime_name: None, mib_enum: 2073, aliases: &["csEBCDICPT"] },
+    Charset { name: "EBCDIC-ES", preferred_mime_name: None, mib_enum: 2074, aliases: &["csEBCDICES"] },
+    Charset { name: "EBCDIC-ES-A", preferred_mime_name: None, mib_enum: 2075, aliases: &["csEBCDICESA"] },
+    Charset { name: "EBCDIC-ES-S", preferred_mime_name: None, mib_enum: 2076, aliases: &["csEBCDICESS"] },
+    Charset { name: "EBCDIC-UK", preferred_mime_name: None, mib_enum: 2077, aliases: &["csEBCDICUK"] },
+    Charset { name: "EBCDIC-US", preferred_mime_name: None, mib_enum: 2078, aliases: &["csEBCDICUS"] },
+    Charset { name: "UNKNOWN-8BIT", preferred_mime_name: None, mib_enum: 2079, aliases: &["csUnknown8BiT"] },
+    Charset { name: "MNEMONIC", preferred_mime_name: None, mib_enum: 2080, aliases: &["csMnemonic"] },
+    Charset { name: "MNEM", preferred_mime_name: None, mib_enum: 2081, aliases: &["csMnem"] },
+    Charset { name: "VISCII", preferred_mime_name: None, mib_enum: 2082, aliases: &["csVISCII"] },
+    Charset { name: "VIQR", preferred_mime_name: None, mib_enum: 2083, aliases: &["csVIQR"] },
+    Charset { name: "KOI8-R", preferred_mime_name: Some("KOI8-R"), mib_enum: 2084, aliases: &["csKOI8R"] },
+    Charset { name: "HZ-GB-2312", preferred_mime_name: Some("HZ-GB-2312"), mib_enum: 2085, aliases: &[] },
+    Charset { name: "IBM866", preferred_mime_name: None, mib_enum: 2086, aliases: &["cp866", "866", "csIBM866"] },
+    Charset { name: "IBM775", preferred_mime_name: None, mib_enum: 2087, aliases: &["cp775", "csPC775Baltic"] },
+    Charset { name: "KOI8-U", preferred_mime_name: None, mib_enum: 2088, aliases: &["csKOI8U"] },
+    Charset { name: "IBM00858", preferred_mime_name: None, mib_enum: 2089, aliases: &["CCSID00858", "CP00858", "PC-Multilingual-850+euro", "csIBM00858"] },
+    Charset { name: "IBM00924", preferred_mime_name: None, mib_enum: 2090, aliases: &["CCSID00924", "CP00924", "ebcdic-Latin9--euro", "csIBM00924"] },
+    Charset { name: "IBM01140", preferred_mime_name: None, mib_enum: 2091, aliases: &["CCSID01140", "CP01140", "ebcdic-us-37+euro", "csIBM01140"] },
+    Charset { name: "IBM01141", preferred_mime_name: None, mib_enum: 2092, aliases: &["CCSID01141", "CP01141", "ebcdic-de-273+euro", "csIBM01141"] },
+    Charset { name: "IBM01142", preferred_mime_name: None, mib_enum: 2093, aliases: &["CCSID01142", "CP01142", "ebcdic-dk-277+euro", "ebcdic-no-277+euro", "csIBM01142"] },
+    Charset { name: "IBM01143", preferred_mime_name: None, mib_enum: 2094, aliases: &["CCSID01143", "CP01143", "ebcdic-fi-278+euro", "ebcdic-se-278+euro", "csIBM01143"] },
+    Charset { name: "IBM01144", preferred_mime_name: None, mib_enum: 2095, aliases: &["CCSID01144", "CP01144", "ebcdic-it-280+euro", "csIBM01144"] },
+    Charset { name: "IBM01145", preferred_mime_name: None, mib_enum: 2096, aliases: &["CCSID01145", "CP01145", "ebcdic-es-284+euro", "csIBM01145"] },
+    Charset { name: "IBM01146", preferred_mime_name: None, mib_enum: 2097, aliases: &["CCSID01146", "CP01146", "ebcdic-gb-285+euro", "csIBM01146"] },
+    Charset { name: "IBM01147", preferred_mime_name: None, mib_enum: 2098, aliases: &["CCSID01147", "CP01147", "ebcdic-fr-297+euro", "csIBM01147"] },
+    Charset { name: "IBM01148", preferred_mime_name: None, mib_enum: 2099, aliases: &["CCSID01148", "CP01148", "ebcdic-international-500+euro", "csIBM01148"] },
+    Charset { name: "IBM01149", preferred_mime_name: None, mib_enum: 2100, aliases: &["CCSID01149", "CP01149", "ebcdic-is-871+euro", "csIBM01149"] },
+    Charset { name: "Big5-HKSCS", preferred_mime_name: None, mib_enum: 2101, aliases: &["csBig5HKSCS"] },
+    Charset { name: "IBM1047", preferred_mime_name: None, mib_enum: 2102, aliases: &["IBM-1047", "csIBM1047"] },
+    Charset { name: "PTCP154", preferred_mime_name: None, mib_enum: 2103, aliases: &["csPTCP154", "PT154", "CP154", "Cyrillic-Asian"] },
+    Charset { name: "Amiga-1251", preferred_mime_name: None, mib_enum: 2104, aliases: &["Ami1251", "Amiga1251", "Ami-1251", "csAmiga1251"] },
+    Charset { name: "KOI7-switched", preferred_mime_name: None, mib_enum: 2105, aliases: &["csKOI7switched"] },
+    Charset { name: "BRF", preferred_mime_name: None, mib_enum: 2106, aliases: &["csBRF"] },
+    Charset { name: "TSCII", preferred_mime_name: None, mib_enum: 2107, aliases: &["csTSCII"] },
+    Charset { name: "CP51932", preferred_mime_name: None, mib_enum: 2108, aliases: &["csCP51932"] },
+    Charset { name: "windows-874", preferred_mime_name: None, mib_enum: 2109, aliases: &["cswindows874"] },
+    Charset { name: "windows-1250", preferred_mime_name: None, mib_enum: 2250, aliases: &["cswindows1250"] },
+    Charset { name: "windows-1251", preferred_mime_name: None, mib_enum: 2251, aliases: &["cswindows1251"] },
+    Charset { name: "windows-1252", preferred_mime_name: None, mib_enum: 2252, aliases: &["cswindows1252"] },
+    Charset { name: "windows-1253", preferred_mime_name: None, mib_enum: 2253, aliases: &["cswindows1253"] },
+    Charset { name: "windows-1254", preferred_mime_name: None, mib_enum: 2254, aliases: &["cswindows1254"] },
+    Charset { name: "windows-1255", preferred_mime_name: None, mib_enum: 2255, aliases: &["cswindows1255"] },
+    Charset { name: "windows-1256", preferred_mime_name: None, mib_enum: 2256, aliases: &["cswindows1256"] },
+    Charset { name: "windows-1257", preferred_mime_name: None, mib_enum: 2257, aliases: &["cswindows1257"] },
+    Charset { name: "windows-1258", preferred_mime_name: None, mib_enum: 2258, aliases: &["cswindows1258"] },
+    Charset { name: "TIS-620", preferred_mime_name: None, mib_enum: 2259, aliases: &["csTIS620", "ISO-8859-11"] },
+    Charset { name: "CP50220", preferred_mime_name: None, mib_enum: 2260, aliases: &["csCP50220"] },
+];
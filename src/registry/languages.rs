@@ -0,0 +1,274 @@
+/*!
+Provides a registry of ISO 639 language codes, enabled by the `languages` feature.
+
+The registry contains every ISO 639-3 code, the ISO 639-2 collective codes that
+have no ISO 639-3 equivalent, and the deprecated codes listed in the IANA
+Language Subtag Registry such as `iw` and `in`. Each entry records the English
+name, the ISO 639-1 and ISO 639-2/B equivalents, scope, type, macrolanguage
+membership and deprecation status.
+
+## Examples
+
+```
+use locale_types::registry::languages::{lookup, LanguageScope, LanguageStatus};
+
+let english = lookup("en").unwrap();
+assert_eq!(english.code(), "eng");
+assert_eq!(english.name(), "English");
+
+let mandarin = lookup("cmn").unwrap();
+assert_eq!(mandarin.macrolanguage(), Some("zho"));
+assert_eq!(lookup("zh").unwrap().scope(), LanguageScope::Macrolanguage);
+
+assert_eq!(lookup("iw").unwrap().status(), LanguageStatus::Deprecated("he"));
+assert!(lookup("zz").is_none());
+```
+
+Strict validation, rejecting well-formed but unknown language codes, is provided
+by `LocaleIdentifier::new_strict` and `LocaleIdentifier::with_language_strict`.
+
+```
+use locale_types::{LocaleError, LocaleIdentifier, LocaleString};
+
+assert!(LocaleString::new_strict("en".to_string()).is_ok());
+assert_eq!(
+    LocaleString::new_strict("zz".to_string()),
+    Err(LocaleError::InvalidLanguageCode)
+);
+```
+*/
+
+mod data;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The scope of a language code, as defined by ISO 639-3 and ISO 639-2.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LanguageScope {
+    /// A single, individual, language.
+    Individual,
+    /// A cluster of closely related individual languages that are, in some
+    /// usage, considered a single language; for example `zh`, or `ar`.
+    Macrolanguage,
+    /// A code that does not denote a language, such as `und` or `mul`.
+    Special,
+    /// An ISO 639-2 code denoting a group of languages, such as `afa`.
+    Collective,
+}
+
+/// The type of an individual language, as defined by ISO 639-3.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LanguageKind {
+    /// A language that has native speakers.
+    Living,
+    /// A language that has died out in recent times.
+    Extinct,
+    /// A language that went extinct in ancient times.
+    Ancient,
+    /// A distinct earlier form of a living language, such as Old English.
+    Historical,
+    /// An artificially devised language, such as Esperanto.
+    Constructed,
+    /// Used for special, and collective, codes.
+    Special,
+}
+
+/// Whether a language code is current, or has been deprecated in favor of another.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LanguageStatus {
+    /// The code is in current use.
+    Active,
+    /// The code is deprecated, the value is the preferred replacement code.
+    Deprecated(&'static str),
+}
+
+/// A single entry in the language registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Language {
+    code: &'static str,
+    alpha_2: Option<&'static str>,
+    bibliographic: Option<&'static str>,
+    name: &'static str,
+    scope: LanguageScope,
+    kind: LanguageKind,
+    macrolanguage: Option<&'static str>,
+    status: LanguageStatus,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Find the registry entry for `code`, which may be an ISO 639-1 two-character code,
+/// an ISO 639-2/T or ISO 639-3 three-character code, an ISO 639-2/B bibliographic
+/// code, or a deprecated code. The comparison is case-insensitive.
+pub fn lookup(code: &str) -> Option<&'static Language> {
+    let code = code.to_ascii_lowercase();
+    match data::LANGUAGES.binary_search_by(|language| language.code.cmp(&code)) {
+        Ok(index) => Some(&data::LANGUAGES[index]),
+        Err(_) => data::LANGUAGES.iter().find(|language| {
+            language.alpha_2 == Some(code.as_str()) || language.bibliographic == Some(code.as_str())
+        }),
+    }
+}
+
+/// Returns `true` if `code` is either in the registry, or is within the range
+/// `qaa`..`qtz` which ISO 639-2 reserves for local use.
+pub fn is_known(code: &str) -> bool {
+    lookup(code).is_some() || is_local_use(code)
+}
+
+/// Return the individual languages that are members of the macrolanguage `code`;
+/// this is empty if `code` is not a macrolanguage.
+pub fn members(code: &str) -> Vec<&'static Language> {
+    match lookup(code) {
+        Some(language) if language.scope == LanguageScope::Macrolanguage => data::LANGUAGES
+            .iter()
+            .filter(|member| member.macrolanguage == Some(language.code))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Return an iterator over all entries in the registry, ordered by code.
+pub fn all() -> impl Iterator<Item = &'static Language> {
+    data::LANGUAGES.iter()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Language {
+    /// Return the ISO 639-3 (or ISO 639-2/T) code for this language; for deprecated
+    /// two-character codes such as `iw` this is the deprecated code itself.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Return the ISO 639-1 two-character code, if one exists.
+    pub fn alpha_2(&self) -> Option<&'static str> {
+        self.alpha_2
+    }
+
+    /// Return the ISO 639-2/B bibliographic code, if it differs from `code`.
+    pub fn bibliographic(&self) -> Option<&'static str> {
+        self.bibliographic
+    }
+
+    /// Return the shortest code for this language, the ISO 639-1 code if one
+    /// exists, which is the form used in locale strings and language tags.
+    pub fn preferred_code(&self) -> &'static str {
+        self.alpha_2.unwrap_or(self.code)
+    }
+
+    /// Return the English reference name; for deprecated codes that were never part
+    /// of ISO 639-3 this is the name of the replacement language.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return the scope of this code.
+    pub fn scope(&self) -> LanguageScope {
+        self.scope
+    }
+
+    /// Return the type of this language.
+    pub fn kind(&self) -> LanguageKind {
+        self.kind
+    }
+
+    /// Return the code of the macrolanguage this language is a member of, if any.
+    pub fn macrolanguage(&self) -> Option<&'static str> {
+        self.macrolanguage
+    }
+
+    /// Return the deprecation status of this code.
+    pub fn status(&self) -> LanguageStatus {
+        self.status
+    }
+
+    /// Returns `true` if this code is deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        matches!(self.status, LanguageStatus::Deprecated(_))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_local_use(code: &str) -> bool {
+    let code = code.as_bytes();
+    code.len() == 3
+        && code[0].eq_ignore_ascii_case(&b'q')
+        && (b'a'..=b't').contains(&code[1].to_ascii_lowercase())
+        && code[2].is_ascii_alphabetic()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_sorted() {
+        assert!(data::LANGUAGES
+            .windows(2)
+            .all(|pair| pair[0].code < pair[1].code));
+    }
+
+    #[test]
+    fn test_lookup_forms() {
+        let french = lookup("fr").unwrap();
+        assert_eq!(french.code(), "fra");
+        assert_eq!(lookup("fra"), Some(french));
+        assert_eq!(lookup("fre"), Some(french));
+        assert_eq!(lookup("FR"), Some(french));
+        assert_eq!(french.bibliographic(), Some("fre"));
+        assert_eq!(french.preferred_code(), "fr");
+        assert_eq!(french.kind(), LanguageKind::Living);
+        assert!(!french.is_deprecated());
+    }
+
+    #[test]
+    fn test_lookup_unknown() {
+        assert!(lookup("zz").is_none());
+        assert!(lookup("xyzzy").is_none());
+        assert!(!is_known("zz"));
+        assert!(is_known("qab"));
+    }
+
+    #[test]
+    fn test_scopes() {
+        assert_eq!(lookup("und").unwrap().scope(), LanguageScope::Special);
+        assert_eq!(lookup("afa").unwrap().scope(), LanguageScope::Collective);
+        assert_eq!(lookup("ar").unwrap().scope(), LanguageScope::Macrolanguage);
+        assert_eq!(lookup("eo").unwrap().kind(), LanguageKind::Constructed);
+    }
+
+    #[test]
+    fn test_macrolanguages() {
+        assert_eq!(lookup("arz").unwrap().macrolanguage(), Some("ara"));
+        assert_eq!(lookup("nb").unwrap().macrolanguage(), Some("nor"));
+        let chinese: Vec<&str> = members("zh").iter().map(|l| l.code()).collect();
+        assert!(chinese.contains(&"cmn"));
+        assert!(chinese.contains(&"yue"));
+        assert!(members("en").is_empty());
+    }
+
+    #[test]
+    fn test_deprecated() {
+        for (old, new) in &[("iw", "he"), ("in", "id"), ("ji", "yi"), ("mo", "ro")] {
+            let language = lookup(old).unwrap();
+            assert!(language.is_deprecated());
+            assert_eq!(language.status(), LanguageStatus::Deprecated(new));
+        }
+        assert_eq!(lookup("iw").unwrap().name(), "Hebrew");
+    }
+}
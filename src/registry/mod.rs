@@ -0,0 +1,59 @@
+/*!
+Provides optional registries of the standard codes used within locale identifiers.
+
+Each registry is an embedded snapshot of the relevant standard and is enabled by
+its own cargo feature, so that applications which only need the structural
+validation provided by `LocaleString` and `LanguageTag` do not pay for the data.
+
+| Feature       | Module                                    | Standard            |
+|---------------|-------------------------------------------|---------------------|
+| `languages`   | [`languages`](languages/index.html)       | ISO 639-1/2/3       |
+| `territories` | [`territories`](territories/index.html)   | ISO 3166-1          |
+| `scripts`     | [`scripts`](scripts/index.html)           | ISO 15924           |
+| `charsets`    | [`charsets`](charsets/index.html)         | IANA Character Sets |
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Modules
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "languages")]
+pub mod languages;
+
+#[cfg(feature = "territories")]
+pub mod territories;
+
+#[cfg(feature = "scripts")]
+pub mod scripts;
+
+#[cfg(feature = "charsets")]
+pub mod charsets;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Returns `true` if `code` is a registered ISO 15924 script code in its title case
+/// form, such as `Latn`, including the private use range `Qaaa`–`Qabx`. Unlike the
+/// `scripts` registry this is always available, as it is needed to tell scripts from
+/// other modifiers.
+pub(crate) fn is_script_code(code: &str) -> bool {
+    code.len() == 4
+        && (script_codes::SCRIPT_CODES.binary_search(&code).is_ok()
+            || ((PRIVATE_USE_FIRST..=PRIVATE_USE_LAST).contains(&code)
+                && code.chars().skip(1).all(|c| c.is_ascii_lowercase())))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Modules
+// ------------------------------------------------------------------------------------------------
+
+mod script_codes;
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const PRIVATE_USE_FIRST: &str = "Qaaa";
+const PRIVATE_USE_LAST: &str = "Qabx";
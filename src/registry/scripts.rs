@@ -0,0 +1,149 @@
+/*!
+Provides a registry of ISO 15924 script codes, enabled by the `scripts` feature.
+
+Each entry records the four-letter code, the numeric code, the English name and
+the direction in which the script is written. Scripts are commonly carried in
+the modifier of a `LocaleString`, either as the ISO 15924 code itself (`@Latn`)
+or as one of the glibc script names such as `@latin` or `@cyrillic`; see
+`LocaleString::script`.
+
+## Examples
+
+```
+use locale_types::registry::scripts::{lookup, Direction};
+
+let arabic = lookup("Arab").unwrap();
+assert_eq!(arabic.name(), "Arabic");
+assert_eq!(arabic.numeric(), "160");
+assert_eq!(arabic.direction(), Direction::RightToLeft);
+assert_eq!(lookup("160"), Some(arabic));
+```
+
+```
+use locale_types::LocaleString;
+use std::str::FromStr;
+
+let locale = LocaleString::from_str("sr_RS.UTF-8@latin").unwrap();
+assert_eq!(locale.script().unwrap().code(), "Latn");
+```
+*/
+
+mod data;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The direction in which a script is written.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    /// The script is written left-to-right, as is Latin.
+    LeftToRight,
+    /// The script is written right-to-left, as are Arabic and Hebrew.
+    RightToLeft,
+    /// The code does not denote a single script, as with `Zyyy` (common) or the
+    /// private use codes, and so has no direction of its own.
+    Unspecified,
+}
+
+/// A single entry in the script registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Script {
+    code: &'static str,
+    numeric: &'static str,
+    name: &'static str,
+    direction: Direction,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Find the registry entry for `code`, which may be the four-letter code, in any
+/// case, or the 3-digit numeric code.
+pub fn lookup(code: &str) -> Option<&'static Script> {
+    if code.len() == 3 && code.chars().all(|c| c.is_ascii_digit()) {
+        return data::SCRIPTS.iter().find(|script| script.numeric == code);
+    }
+    data::SCRIPTS
+        .binary_search_by(|script| {
+            script
+                .code
+                .bytes()
+                .map(|b| b.to_ascii_lowercase())
+                .cmp(code.bytes().map(|b| b.to_ascii_lowercase()))
+        })
+        .ok()
+        .map(|index| &data::SCRIPTS[index])
+}
+
+/// Return an iterator over all entries in the registry, ordered by code.
+pub fn all() -> impl Iterator<Item = &'static Script> {
+    data::SCRIPTS.iter()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Script {
+    /// Return the four-letter ISO 15924 code, in title case, such as `Latn`.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Return the 3-digit ISO 15924 numeric code.
+    pub fn numeric(&self) -> &'static str {
+        self.numeric
+    }
+
+    /// Return the English name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return the direction in which this script is written.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns `true` if this script is written right-to-left.
+    pub fn is_right_to_left(&self) -> bool {
+        self.direction == Direction::RightToLeft
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_sorted() {
+        assert!(data::SCRIPTS
+            .windows(2)
+            .all(|pair| pair[0].code.to_ascii_lowercase() < pair[1].code.to_ascii_lowercase()));
+    }
+
+    #[test]
+    fn test_lookup_forms() {
+        let latin = lookup("Latn").unwrap();
+        assert_eq!(latin.name(), "Latin");
+        assert_eq!(lookup("latn"), Some(latin));
+        assert_eq!(lookup("LATN"), Some(latin));
+        assert_eq!(lookup("215"), Some(latin));
+        assert!(lookup("Xxxx").is_none());
+        assert!(lookup("latin").is_none());
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(lookup("Cyrl").unwrap().direction(), Direction::LeftToRight);
+        assert!(lookup("Hebr").unwrap().is_right_to_left());
+        assert!(lookup("Thaa").unwrap().is_right_to_left());
+        assert_eq!(lookup("Zyyy").unwrap().direction(), Direction::Unspecified);
+    }
+}
@@ -0,0 +1,191 @@
+// Generated from the Debian iso-codes ISO 15924 table, with directionality taken
+// from the Unicode Character Database; do not edit by hand.
+
+use super::Direction::*;
+use super::Script;
+
+#[rustfmt::skip]
+pub(super) static SCRIPTS: [Script; 182] = [
+    Script { code: "Adlm", numeric: "166", name: "Adlam", direction: RightToLeft },
+    Script { code: "Afak", numeric: "439", name: "Afaka", direction: LeftToRight },
+    Script { code: "Aghb", numeric: "239", name: "Caucasian Albanian", direction: LeftToRight },
+    Script { code: "Ahom", numeric: "338", name: "Ahom, Tai Ahom", direction: LeftToRight },
+    Script { code: "Arab", numeric: "160", name: "Arabic", direction: RightToLeft },
+    Script { code: "Aran", numeric: "161", name: "Arabic (Nastaliq variant)", direction: RightToLeft },
+    Script { code: "Armi", numeric: "124", name: "Imperial Aramaic", direction: RightToLeft },
+    Script { code: "Armn", numeric: "230", name: "Armenian", direction: LeftToRight },
+    Script { code: "Avst", numeric: "134", name: "Avestan", direction: RightToLeft },
+    Script { code: "Bali", numeric: "360", name: "Balinese", direction: LeftToRight },
+    Script { code: "Bamu", numeric: "435", name: "Bamum", direction: LeftToRight },
+    Script { code: "Bass", numeric: "259", name: "Bassa Vah", direction: LeftToRight },
+    Script { code: "Batk", numeric: "365", name: "Batak", direction: LeftToRight },
+    Script { code: "Beng", numeric: "325", name: "Bengali", direction: LeftToRight },
+    Script { code: "Bhks", numeric: "334", name: "Bhaiksuki", direction: LeftToRight },
+    Script { code: "Blis", numeric: "550", name: "Blissymbols", direction: LeftToRight },
+    Script { code: "Bopo", numeric: "285", name: "Bopomofo", direction: LeftToRight },
+    Script { code: "Brah", numeric: "300", name: "Brahmi", direction: LeftToRight },
+    Script { code: "Brai", numeric: "570", name: "Braille", direction: LeftToRight },
+    Script { code: "Bugi", numeric: "367", name: "Buginese", direction: LeftToRight },
+    Script { code: "Buhd", numeric: "372", name: "Buhid", direction: LeftToRight },
+    Script { code: "Cakm", numeric: "349", name: "Chakma", direction: LeftToRight },
+    Script { code: "Cans", numeric: "440", name: "Unified Canadian Aboriginal Syllabics", direction: LeftToRight },
+    Script { code: "Cari", numeric: "201", name: "Carian", direction: LeftToRight },
+    Script { code: "Cham", numeric: "358", name: "Cham", direction: LeftToRight },
+    Script { code: "Cher", numeric: "445", name: "Cherokee", direction: LeftToRight },
+    Script { code: "Cirt", numeric: "291", name: "Cirth", direction: LeftToRight },
+    Script { code: "Copt", numeric: "204", name: "Coptic", direction: LeftToRight },
+    Script { code: "Cprt", numeric: "403", name: "Cypriot", direction: RightToLeft },
+    Script { code: "Cyrl", numeric: "220", name: "Cyrillic", direction: LeftToRight },
+    Script { code: "Cyrs", numeric: "221", name: "Cyrillic (Old Church Slavonic variant)", direction: LeftToRight },
+    Script { code: "Deva", numeric: "315", name: "Devanagari (Nagari)", direction: LeftToRight },
+    Script { code: "Dsrt", numeric: "250", name: "Deseret (Mormon)", direction: LeftToRight },
+    Script { code: "Dupl", numeric: "755", name: "Duployan shorthand, Duployan stenography", direction: LeftToRight },
+    Script { code: "Egyd", numeric: "070", name: "Egyptian demotic", direction: LeftToRight },
+    Script { code: "Egyh", numeric: "060", name: "Egyptian hieratic", direction: LeftToRight },
+    Script { code: "Egyp", numeric: "050", name: "Egyptian hieroglyphs", direction: LeftToRight },
+    Script { code: "Elba", numeric: "226", name: "Elbasan", direction: LeftToRight },
+    Script { code: "Ethi", numeric: "430", name: "Ethiopic (Geʻez)", direction: LeftToRight },
+    Script { code: "Geok", numeric: "241", name: "Khutsuri (Asomtavruli and Nuskhuri)", direction: LeftToRight },
+    Script { code: "Geor", numeric: "240", name: "Georgian (Mkhedruli)", direction: LeftToRight },
+    Script { code: "Glag", numeric: "225", name: "Glagolitic", direction: LeftToRight },
+    Script { code: "Goth", numeric: "206", name: "Gothic", direction: LeftToRight },
+    Script { code: "Gran", numeric: "343", name: "Grantha", direction: LeftToRight },
+    Script { code: "Grek", numeric: "200", name: "Greek", direction: LeftToRight },
+    Script { code: "Gujr", numeric: "320", name: "Gujarati", direction: LeftToRight },
+    Script { code: "Guru", numeric: "310", name: "Gurmukhi", direction: LeftToRight },
+    Script { code: "Hanb", numeric: "503", name: "Han with Bopomofo (alias for Han + Bopomofo)", direction: LeftToRight },
+    Script { code: "Hang", numeric: "286", name: "Hangul (Hangŭl, Hangeul)", direction: LeftToRight },
+    Script { code: "Hani", numeric: "500", name: "Han (Hanzi, Kanji, Hanja)", direction: LeftToRight },
+    Script { code: "Hano", numeric: "371", name: "Hanunoo (Hanunóo)", direction: LeftToRight },
+    Script { code: "Hans", numeric: "501", name: "Han (Simplified variant)", direction: LeftToRight },
+    Script { code: "Hant", numeric: "502", name: "Han (Traditional variant)", direction: LeftToRight },
+    Script { code: "Hatr", numeric: "127", name: "Hatran", direction: RightToLeft },
+    Script { code: "Hebr", numeric: "125", name: "Hebrew", direction: RightToLeft },
+    Script { code: "Hira", numeric: "410", name: "Hiragana", direction: LeftToRight },
+    Script { code: "Hluw", numeric: "080", name: "Anatolian Hieroglyphs (Luwian Hieroglyphs, Hittite Hieroglyphs)", direction: LeftToRight },
+    Script { code: "Hmng", numeric: "450", name: "Pahawh Hmong", direction: LeftToRight },
+    Script { code: "Hrkt", numeric: "412", name: "Japanese syllabaries (alias for Hiragana + Katakana)", direction: LeftToRight },
+    Script { code: "Hung", numeric: "176", name: "Old Hungarian (Hungarian Runic)", direction: RightToLeft },
+    Script { code: "Inds", numeric: "610", name: "Indus (Harappan)", direction: LeftToRight },
+    Script { code: "Ital", numeric: "210", name: "Old Italic (Etruscan, Oscan, etc.)", direction: LeftToRight },
+    Script { code: "Jamo", numeric: "284", name: "Jamo (alias for Jamo subset of Hangul)", direction: LeftToRight },
+    Script { code: "Java", numeric: "361", name: "Javanese", direction: LeftToRight },
+    Script { code: "Jpan", numeric: "413", name: "Japanese (alias for Han + Hiragana + Katakana)", direction: LeftToRight },
+    Script { code: "Jurc", numeric: "510", name: "Jurchen", direction: LeftToRight },
+    Script { code: "Kali", numeric: "357", name: "Kayah Li", direction: LeftToRight },
+    Script { code: "Kana", numeric: "411", name: "Katakana", direction: LeftToRight },
+    Script { code: "Khar", numeric: "305", name: "Kharoshthi", direction: RightToLeft },
+    Script { code: "Khmr", numeric: "355", name: "Khmer", direction: LeftToRight },
+    Script { code: "Khoj", numeric: "322", name: "Khojki", direction: LeftToRight },
+    Script { code: "Kitl", numeric: "505", name: "Khitan large script", direction: LeftToRight },
+    Script { code: "Kits", numeric: "288", name: "Khitan small script", direction: LeftToRight },
+    Script { code: "Knda", numeric: "345", name: "Kannada", direction: LeftToRight },
+    Script { code: "Kore", numeric: "287", name: "Korean (alias for Hangul + Han)", direction: LeftToRight },
+    Script { code: "Kpel", numeric: "436", name: "Kpelle", direction: LeftToRight },
+    Script { code: "Kthi", numeric: "317", name: "Kaithi", direction: LeftToRight },
+    Script { code: "Lana", numeric: "351", name: "Tai Tham (Lanna)", direction: LeftToRight },
+    Script { code: "Laoo", numeric: "356", name: "Lao", direction: LeftToRight },
+    Script { code: "Latf", numeric: "217", name: "Latin (Fraktur variant)", direction: LeftToRight },
+    Script { code: "Latg", numeric: "216", name: "Latin (Gaelic variant)", direction: LeftToRight },
+    Script { code: "Latn", numeric: "215", name: "Latin", direction: LeftToRight },
+    Script { code: "Leke", numeric: "364", name: "Leke", direction: LeftToRight },
+    Script { code: "Lepc", numeric: "335", name: "Lepcha (Róng)", direction: LeftToRight },
+    Script { code: "Limb", numeric: "336", name: "Limbu", direction: LeftToRight },
+    Script { code: "Lina", numeric: "400", name: "Linear A", direction: LeftToRight },
+    Script { code: "Linb", numeric: "401", name: "Linear B", direction: LeftToRight },
+    Script { code: "Lisu", numeric: "399", name: "Lisu (Fraser)", direction: LeftToRight },
+    Script { code: "Loma", numeric: "437", name: "Loma", direction: LeftToRight },
+    Script { code: "Lyci", numeric: "202", name: "Lycian", direction: LeftToRight },
+    Script { code: "Lydi", numeric: "116", name: "Lydian", direction: RightToLeft },
+    Script { code: "Mahj", numeric: "314", name: "Mahajani", direction: LeftToRight },
+    Script { code: "Mand", numeric: "140", name: "Mandaic, Mandaean", direction: RightToLeft },
+    Script { code: "Mani", numeric: "139", name: "Manichaean", direction: RightToLeft },
+    Script { code: "Marc", numeric: "332", name: "Marchen", direction: LeftToRight },
+    Script { code: "Maya", numeric: "090", name: "Mayan hieroglyphs", direction: LeftToRight },
+    Script { code: "Mend", numeric: "438", name: "Mende Kikakui", direction: RightToLeft },
+    Script { code: "Merc", numeric: "101", name: "Meroitic Cursive", direction: RightToLeft },
+    Script { code: "Mero", numeric: "100", name: "Meroitic Hieroglyphs", direction: RightToLeft },
+    Script { code: "Mlym", numeric: "347", name: "Malayalam", direction: LeftToRight },
+    Script { code: "Modi", numeric: "324", name: "Modi, Moḍī", direction: LeftToRight },
+    Script { code: "Mong", numeric: "145", name: "Mongolian", direction: LeftToRight },
+    Script { code: "Moon", numeric: "218", name: "Moon (Moon code, Moon script, Moon type)", direction: LeftToRight },
+    Script { code: "Mroo", numeric: "199", name: "Mro, Mru", direction: LeftToRight },
+    Script { code: "Mtei", numeric: "337", name: "Meitei Mayek (Meithei, Meetei)", direction: LeftToRight },
+    Script { code: "Mult", numeric: "323", name: "Multani", direction: LeftToRight },
+    Script { code: "Mymr", numeric: "350", name: "Myanmar (Burmese)", direction: LeftToRight },
+    Script { code: "Narb", numeric: "106", name: "Old North Arabian (Ancient North Arabian)", direction: RightToLeft },
+    Script { code: "Nbat", numeric: "159", name: "Nabataean", direction: RightToLeft },
+    Script { code: "Newa", numeric: "333", name: "Newa, Newar, Newari, Nepāla lipi", direction: LeftToRight },
+    Script { code: "Nkgb", numeric: "420", name: "Nakhi Geba ('Na-'Khi ²Ggŏ-¹baw, Naxi Geba)", direction: LeftToRight },
+    Script { code: "Nkoo", numeric: "165", name: "N’Ko", direction: RightToLeft },
+    Script { code: "Nshu", numeric: "499", name: "Nüshu", direction: LeftToRight },
+    Script { code: "Ogam", numeric: "212", name: "Ogham", direction: LeftToRight },
+    Script { code: "Olck", numeric: "261", name: "Ol Chiki (Ol Cemet’, Ol, Santali)", direction: LeftToRight },
+    Script { code: "Orkh", numeric: "175", name: "Old Turkic, Orkhon Runic", direction: RightToLeft },
+    Script { code: "Orya", numeric: "327", name: "Oriya", direction: LeftToRight },
+    Script { code: "Osge", numeric: "219", name: "Osage", direction: LeftToRight },
+    Script { code: "Osma", numeric: "260", name: "Osmanya", direction: LeftToRight },
+    Script { code: "Palm", numeric: "126", name: "Palmyrene", direction: RightToLeft },
+    Script { code: "Pauc", numeric: "263", name: "Pau Cin Hau", direction: LeftToRight },
+    Script { code: "Perm", numeric: "227", name: "Old Permic", direction: LeftToRight },
+    Script { code: "Phag", numeric: "331", name: "Phags-pa", direction: LeftToRight },
+    Script { code: "Phli", numeric: "131", name: "Inscriptional Pahlavi", direction: RightToLeft },
+    Script { code: "Phlp", numeric: "132", name: "Psalter Pahlavi", direction: RightToLeft },
+    Script { code: "Phlv", numeric: "133", name: "Book Pahlavi", direction: RightToLeft },
+    Script { code: "Phnx", numeric: "115", name: "Phoenician", direction: RightToLeft },
+    Script { code: "Piqd", numeric: "293", name: "Klingon (KLI pIqaD)", direction: LeftToRight },
+    Script { code: "Plrd", numeric: "282", name: "Miao (Pollard)", direction: LeftToRight },
+    Script { code: "Prti", numeric: "130", name: "Inscriptional Parthian", direction: RightToLeft },
+    Script { code: "Qaaa", numeric: "900", name: "Reserved for private use (start)", direction: Unspecified },
+    Script { code: "Qabx", numeric: "949", name: "Reserved for private use (end)", direction: Unspecified },
+    Script { code: "Rjng", numeric: "363", name: "Rejang (Redjang, Kaganga)", direction: LeftToRight },
+    Script { code: "Roro", numeric: "620", name: "Rongorongo", direction: LeftToRight },
+    Script { code: "Runr", numeric: "211", name: "Runic", direction: LeftToRight },
+    Script { code: "Samr", numeric: "123", name: "Samaritan", direction: RightToLeft },
+    Script { code: "Sara", numeric: "292", name: "Sarati", direction: LeftToRight },
+    Script { code: "Sarb", numeric: "105", name: "Old South Arabian", direction: RightToLeft },
+    Script { code: "Saur", numeric: "344", name: "Saurashtra", direction: LeftToRight },
+    Script { code: "Sgnw", numeric: "095", name: "SignWriting", direction: LeftToRight },
+    Script { code: "Shaw", numeric: "281", name: "Shavian (Shaw)", direction: LeftToRight },
+    Script { code: "Shrd", numeric: "319", name: "Sharada, Śāradā", direction: LeftToRight },
+    Script { code: "Sidd", numeric: "302", name: "Siddham, Siddhaṃ, Siddhamātṛkā", direction: LeftToRight },
+    Script { code: "Sind", numeric: "318", name: "Khudawadi, Sindhi", direction: LeftToRight },
+    Script { code: "Sinh", numeric: "348", name: "Sinhala", direction: LeftToRight },
+    Script { code: "Sora", numeric: "398", name: "Sora Sompeng", direction: LeftToRight },
+    Script { code: "Sund", numeric: "362", name: "Sundanese", direction: LeftToRight },
+    Script { code: "Sylo", numeric: "316", name: "Syloti Nagri", direction: LeftToRight },
+    Script { code: "Syrc", numeric: "135", name: "Syriac", direction: RightToLeft },
+    Script { code: "Syre", numeric: "138", name: "Syriac (Estrangelo variant)", direction: RightToLeft },
+    Script { code: "Syrj", numeric: "137", name: "Syriac (Western variant)", direction: RightToLeft },
+    Script { code: "Syrn", numeric: "136", name: "Syriac (Eastern variant)", direction: RightToLeft },
+    Script { code: "Tagb", numeric: "373", name: "Tagbanwa", direction: LeftToRight },
+    Script { code: "Takr", numeric: "321", name: "Takri, Ṭākrī, Ṭāṅkrī", direction: LeftToRight },
+    Script { code: "Tale", numeric: "353", name: "Tai Le", direction: LeftToRight },
+    Script { code: "Talu", numeric: "354", name: "New Tai Lue", direction: LeftToRight },
+    Script { code: "Taml", numeric: "346", name: "Tamil", direction: LeftToRight },
+    Script { code: "Tang", numeric: "520", name: "Tangut", direction: LeftToRight },
+    Script { code: "Tavt", numeric: "359", name: "Tai Viet", direction: LeftToRight },
+    Script { code: "Telu", numeric: "340", name: "Telugu", direction: LeftToRight },
+    Script { code: "Teng", numeric: "290", name: "Tengwar", direction: LeftToRight },
+    Script { code: "Tfng", numeric: "120", name: "Tifinagh (Berber)", direction: LeftToRight },
+    Script { code: "Tglg", numeric: "370", name: "Tagalog (Baybayin, Alibata)", direction: LeftToRight },
+    Script { code: "Thaa", numeric: "170", name: "Thaana", direction: RightToLeft },
+    Script { code: "Thai", numeric: "352", name: "Thai", direction: LeftToRight },
+    Script { code: "Tibt", numeric: "330", name: "Tibetan", direction: LeftToRight },
+    Script { code: "Tirh", numeric: "326", name: "Tirhuta", direction: LeftToRight },
+    Script { code: "Ugar", numeric: "040", name: "Ugaritic", direction: LeftToRight },
+    Script { code: "Vaii", numeric: "470", name: "Vai", direction: LeftToRight },
+    Script { code: "Visp", numeric: "280", name: "Visible Speech", direction: LeftToRight },
+    Script { code: "Wara", numeric: "262", name: "Warang Citi (Varang Kshiti)", direction: LeftToRight },
+    Script { code: "Wole", numeric: "480", name: "Woleai", direction: LeftToRight },
+    Script { code: "Xpeo", numeric: "030", name: "Old Persian", direction: LeftToRight },
+    Script { code: "Xsux", numeric: "020", name: "Cuneiform, Sumero-Akkadian", direction: LeftToRight },
+    Script { code: "Yiii", numeric: "460", name: "Yi", direction: LeftToRight },
+    Script { code: "Zinh", numeric: "994", name: "Code for inherited script", direction: Unspecified },
+    Script { code: "Zmth", numeric: "995", name: "Mathematical notation", direction: Unspecified },
+    Script { code: "Zsye", numeric: "993", name: "Symbols (Emoji variant)", direction: Unspecified },
+    Script { code: "Zsym", numeric: "996", name: "Symbols", direction: Unspecified },
+    Script { code: "Zxxx", numeric: "997", name: "Code for unwritten documents", direction: Unspecified },
+    Script { code: "Zyyy", numeric: "998", name: "Code for undetermined script", direction: Unspecified },
+    Script { code: "Zzzz", numeric: "999", name: "Code for uncoded script", direction: Unspecified },
+];
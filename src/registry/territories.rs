@@ -0,0 +1,211 @@
+/*!
+Provides a registry of ISO 3166-1 territory codes, enabled by the `territories` feature.
+
+The registry contains every officially assigned ISO 3166-1 code, as well as the
+exceptionally reserved, transitionally reserved and withdrawn codes which are
+still commonly found in locale identifiers, such as `UK`, `EU` or `YU`. Each entry
+records the alpha-2, alpha-3 and numeric codes, the English name, the status and,
+where there is a single obvious choice, the code that should be used instead.
+
+## Examples
+
+```
+use locale_types::registry::territories::{lookup, suggest, TerritoryStatus};
+
+let germany = lookup("DE").unwrap();
+assert_eq!(germany.alpha_3(), Some("DEU"));
+assert_eq!(germany.numeric(), Some("276"));
+assert_eq!(lookup("276"), Some(germany));
+
+let uk = lookup("UK").unwrap();
+assert_eq!(uk.status(), TerritoryStatus::ExceptionallyReserved);
+assert_eq!(suggest("UK"), Some("GB"));
+```
+
+Strict validation, rejecting unknown and reserved territory codes, is provided
+by `LocaleIdentifier::with_territory_strict`.
+
+```
+use locale_types::{LocaleError, LocaleIdentifier, LocaleString};
+
+let locale = LocaleString::new("en".to_string()).unwrap();
+assert!(locale.with_territory_strict("GB".to_string()).is_ok());
+assert!(locale.with_territory_strict("419".to_string()).is_ok());
+assert_eq!(
+    locale.with_territory_strict("UK".to_string()),
+    Err(LocaleError::InvalidTerritoryCode)
+);
+```
+*/
+
+use crate::string::TerritoryKind;
+
+mod data;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The status of an ISO 3166-1 alpha-2 code element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TerritoryStatus {
+    /// The code is officially assigned to a country or territory.
+    Assigned,
+    /// The code is reserved at the request of a national or international body,
+    /// such as `UK` or `EU`, but is not assigned to a country.
+    ExceptionallyReserved,
+    /// The code has been withdrawn, but remains reserved for a transitional period
+    /// as it is still in use, such as `YU` or `TP`.
+    TransitionallyReserved,
+    /// The code has been withdrawn and is no longer reserved.
+    Withdrawn,
+}
+
+/// A single entry in the territory registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Territory {
+    alpha_2: &'static str,
+    alpha_3: Option<&'static str>,
+    numeric: Option<&'static str>,
+    name: &'static str,
+    status: TerritoryStatus,
+    replacement: Option<&'static str>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Find the registry entry for `code`, which may be an alpha-2, alpha-3 or 3-digit
+/// numeric code. The comparison is case-insensitive; where a withdrawn code shares
+/// its alpha-3 or numeric code with an assigned one the assigned entry is returned.
+pub fn lookup(code: &str) -> Option<&'static Territory> {
+    let code = code.to_ascii_uppercase();
+    if let Ok(index) = data::TERRITORIES.binary_search_by(|t| t.alpha_2.cmp(&code)) {
+        return Some(&data::TERRITORIES[index]);
+    }
+    let matches =
+        |t: &&Territory| t.alpha_3 == Some(code.as_str()) || t.numeric == Some(code.as_str());
+    data::TERRITORIES
+        .iter()
+        .filter(|t| t.is_assigned())
+        .find(matches)
+        .or_else(|| data::TERRITORIES.iter().find(matches))
+}
+
+/// Returns `true` if `code` is an officially assigned ISO 3166-1 code, or is
+/// a UN M.49 macro-region code such as `419`.
+pub fn is_known(code: &str) -> bool {
+    TerritoryKind::of(code) == Some(TerritoryKind::MacroRegion)
+        || lookup(code).is_some_and(|t| t.is_assigned())
+}
+
+/// Return the assigned alpha-2 code that should be used in place of `code`; this
+/// is `None` if `code` is already assigned, unknown, or has no single replacement.
+pub fn suggest(code: &str) -> Option<&'static str> {
+    lookup(code).and_then(|t| t.replacement)
+}
+
+/// Return an iterator over all entries in the registry, ordered by alpha-2 code.
+pub fn all() -> impl Iterator<Item = &'static Territory> {
+    data::TERRITORIES.iter()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Territory {
+    /// Return the ISO 3166-1 alpha-2 code, the form used in locale identifiers.
+    pub fn alpha_2(&self) -> &'static str {
+        self.alpha_2
+    }
+
+    /// Return the ISO 3166-1 alpha-3 code, if one exists.
+    pub fn alpha_3(&self) -> Option<&'static str> {
+        self.alpha_3
+    }
+
+    /// Return the ISO 3166-1 numeric code, as 3 digits, if one exists.
+    pub fn numeric(&self) -> Option<&'static str> {
+        self.numeric
+    }
+
+    /// Return the English short name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return the status of this code.
+    pub fn status(&self) -> TerritoryStatus {
+        self.status
+    }
+
+    /// Return the assigned alpha-2 code that replaces this one, if any.
+    pub fn replacement(&self) -> Option<&'static str> {
+        self.replacement
+    }
+
+    /// Returns `true` if this code is officially assigned.
+    pub fn is_assigned(&self) -> bool {
+        self.status == TerritoryStatus::Assigned
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_sorted() {
+        assert!(data::TERRITORIES
+            .windows(2)
+            .all(|pair| pair[0].alpha_2 < pair[1].alpha_2));
+    }
+
+    #[test]
+    fn test_lookup_forms() {
+        let us = lookup("US").unwrap();
+        assert_eq!(us.name(), "United States");
+        assert_eq!(lookup("us"), Some(us));
+        assert_eq!(lookup("USA"), Some(us));
+        assert_eq!(lookup("840"), Some(us));
+        assert!(us.is_assigned());
+        assert!(lookup("XY").is_none());
+    }
+
+    #[test]
+    fn test_shared_codes_prefer_assigned() {
+        assert_eq!(lookup("180").unwrap().alpha_2(), "CD");
+        assert_eq!(lookup("ATF").unwrap().alpha_2(), "TF");
+        assert_eq!(lookup("ZR").unwrap().replacement(), Some("CD"));
+    }
+
+    #[test]
+    fn test_reserved() {
+        assert_eq!(
+            lookup("EU").unwrap().status(),
+            TerritoryStatus::ExceptionallyReserved
+        );
+        assert_eq!(
+            lookup("YU").unwrap().status(),
+            TerritoryStatus::TransitionallyReserved
+        );
+        assert_eq!(lookup("DD").unwrap().status(), TerritoryStatus::Withdrawn);
+        assert_eq!(suggest("UK"), Some("GB"));
+        assert_eq!(suggest("GB"), None);
+    }
+
+    #[test]
+    fn test_is_known() {
+        assert!(is_known("GB"));
+        assert!(is_known("150"));
+        assert!(is_known("826"));
+        assert!(!is_known("UK"));
+        assert!(!is_known("QQ"));
+    }
+}
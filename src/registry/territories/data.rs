@@ -0,0 +1,295 @@
+// Generated from the Debian iso-codes ISO 3166-1 and ISO 3166-3 tables, with the
+// ISO 3166 reserved code elements added by hand; do not edit by hand.
+
+use super::Territory;
+use super::TerritoryStatus::*;
+
+#[rustfmt::skip]
+pub(super) static TERRITORIES: [Territory; 286] = [
+    Territory { alpha_2: "AC", alpha_3: None, numeric: None, name: "Ascension Island", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "AD", alpha_3: Some("AND"), numeric: Some("020"), name: "Andorra", status: Assigned, replacement: None },
+    Territory { alpha_2: "AE", alpha_3: Some("ARE"), numeric: Some("784"), name: "United Arab Emirates", status: Assigned, replacement: None },
+    Territory { alpha_2: "AF", alpha_3: Some("AFG"), numeric: Some("004"), name: "Afghanistan", status: Assigned, replacement: None },
+    Territory { alpha_2: "AG", alpha_3: Some("ATG"), numeric: Some("028"), name: "Antigua and Barbuda", status: Assigned, replacement: None },
+    Territory { alpha_2: "AI", alpha_3: Some("AIA"), numeric: Some("660"), name: "Anguilla", status: Assigned, replacement: None },
+    Territory { alpha_2: "AL", alpha_3: Some("ALB"), numeric: Some("008"), name: "Albania", status: Assigned, replacement: None },
+    Territory { alpha_2: "AM", alpha_3: Some("ARM"), numeric: Some("051"), name: "Armenia", status: Assigned, replacement: None },
+    Territory { alpha_2: "AN", alpha_3: Some("ANT"), numeric: Some("530"), name: "Netherlands Antilles", status: TransitionallyReserved, replacement: None },
+    Territory { alpha_2: "AO", alpha_3: Some("AGO"), numeric: Some("024"), name: "Angola", status: Assigned, replacement: None },
+    Territory { alpha_2: "AQ", alpha_3: Some("ATA"), numeric: Some("010"), name: "Antarctica", status: Assigned, replacement: None },
+    Territory { alpha_2: "AR", alpha_3: Some("ARG"), numeric: Some("032"), name: "Argentina", status: Assigned, replacement: None },
+    Territory { alpha_2: "AS", alpha_3: Some("ASM"), numeric: Some("016"), name: "American Samoa", status: Assigned, replacement: None },
+    Territory { alpha_2: "AT", alpha_3: Some("AUT"), numeric: Some("040"), name: "Austria", status: Assigned, replacement: None },
+    Territory { alpha_2: "AU", alpha_3: Some("AUS"), numeric: Some("036"), name: "Australia", status: Assigned, replacement: None },
+    Territory { alpha_2: "AW", alpha_3: Some("ABW"), numeric: Some("533"), name: "Aruba", status: Assigned, replacement: None },
+    Territory { alpha_2: "AX", alpha_3: Some("ALA"), numeric: Some("248"), name: "Åland Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "AZ", alpha_3: Some("AZE"), numeric: Some("031"), name: "Azerbaijan", status: Assigned, replacement: None },
+    Territory { alpha_2: "BA", alpha_3: Some("BIH"), numeric: Some("070"), name: "Bosnia and Herzegovina", status: Assigned, replacement: None },
+    Territory { alpha_2: "BB", alpha_3: Some("BRB"), numeric: Some("052"), name: "Barbados", status: Assigned, replacement: None },
+    Territory { alpha_2: "BD", alpha_3: Some("BGD"), numeric: Some("050"), name: "Bangladesh", status: Assigned, replacement: None },
+    Territory { alpha_2: "BE", alpha_3: Some("BEL"), numeric: Some("056"), name: "Belgium", status: Assigned, replacement: None },
+    Territory { alpha_2: "BF", alpha_3: Some("BFA"), numeric: Some("854"), name: "Burkina Faso", status: Assigned, replacement: None },
+    Territory { alpha_2: "BG", alpha_3: Some("BGR"), numeric: Some("100"), name: "Bulgaria", status: Assigned, replacement: None },
+    Territory { alpha_2: "BH", alpha_3: Some("BHR"), numeric: Some("048"), name: "Bahrain", status: Assigned, replacement: None },
+    Territory { alpha_2: "BI", alpha_3: Some("BDI"), numeric: Some("108"), name: "Burundi", status: Assigned, replacement: None },
+    Territory { alpha_2: "BJ", alpha_3: Some("BEN"), numeric: Some("204"), name: "Benin", status: Assigned, replacement: None },
+    Territory { alpha_2: "BL", alpha_3: Some("BLM"), numeric: Some("652"), name: "Saint Barthélemy", status: Assigned, replacement: None },
+    Territory { alpha_2: "BM", alpha_3: Some("BMU"), numeric: Some("060"), name: "Bermuda", status: Assigned, replacement: None },
+    Territory { alpha_2: "BN", alpha_3: Some("BRN"), numeric: Some("096"), name: "Brunei Darussalam", status: Assigned, replacement: None },
+    Territory { alpha_2: "BO", alpha_3: Some("BOL"), numeric: Some("068"), name: "Bolivia, Plurinational State of", status: Assigned, replacement: None },
+    Territory { alpha_2: "BQ", alpha_3: Some("BES"), numeric: Some("535"), name: "Bonaire, Sint Eustatius and Saba", status: Assigned, replacement: None },
+    Territory { alpha_2: "BR", alpha_3: Some("BRA"), numeric: Some("076"), name: "Brazil", status: Assigned, replacement: None },
+    Territory { alpha_2: "BS", alpha_3: Some("BHS"), numeric: Some("044"), name: "Bahamas", status: Assigned, replacement: None },
+    Territory { alpha_2: "BT", alpha_3: Some("BTN"), numeric: Some("064"), name: "Bhutan", status: Assigned, replacement: None },
+    Territory { alpha_2: "BU", alpha_3: Some("BUR"), numeric: Some("104"), name: "Burma, Socialist Republic of the Union of", status: TransitionallyReserved, replacement: Some("MM") },
+    Territory { alpha_2: "BV", alpha_3: Some("BVT"), numeric: Some("074"), name: "Bouvet Island", status: Assigned, replacement: None },
+    Territory { alpha_2: "BW", alpha_3: Some("BWA"), numeric: Some("072"), name: "Botswana", status: Assigned, replacement: None },
+    Territory { alpha_2: "BY", alpha_3: Some("BLR"), numeric: Some("112"), name: "Belarus", status: Assigned, replacement: None },
+    Territory { alpha_2: "BZ", alpha_3: Some("BLZ"), numeric: Some("084"), name: "Belize", status: Assigned, replacement: None },
+    Territory { alpha_2: "CA", alpha_3: Some("CAN"), numeric: Some("124"), name: "Canada", status: Assigned, replacement: None },
+    Territory { alpha_2: "CC", alpha_3: Some("CCK"), numeric: Some("166"), name: "Cocos (Keeling) Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "CD", alpha_3: Some("COD"), numeric: Some("180"), name: "Congo, The Democratic Republic of the", status: Assigned, replacement: None },
+    Territory { alpha_2: "CF", alpha_3: Some("CAF"), numeric: Some("140"), name: "Central African Republic", status: Assigned, replacement: None },
+    Territory { alpha_2: "CG", alpha_3: Some("COG"), numeric: Some("178"), name: "Congo", status: Assigned, replacement: None },
+    Territory { alpha_2: "CH", alpha_3: Some("CHE"), numeric: Some("756"), name: "Switzerland", status: Assigned, replacement: None },
+    Territory { alpha_2: "CI", alpha_3: Some("CIV"), numeric: Some("384"), name: "Côte d'Ivoire", status: Assigned, replacement: None },
+    Territory { alpha_2: "CK", alpha_3: Some("COK"), numeric: Some("184"), name: "Cook Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "CL", alpha_3: Some("CHL"), numeric: Some("152"), name: "Chile", status: Assigned, replacement: None },
+    Territory { alpha_2: "CM", alpha_3: Some("CMR"), numeric: Some("120"), name: "Cameroon", status: Assigned, replacement: None },
+    Territory { alpha_2: "CN", alpha_3: Some("CHN"), numeric: Some("156"), name: "China", status: Assigned, replacement: None },
+    Territory { alpha_2: "CO", alpha_3: Some("COL"), numeric: Some("170"), name: "Colombia", status: Assigned, replacement: None },
+    Territory { alpha_2: "CP", alpha_3: None, numeric: None, name: "Clipperton Island", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "CQ", alpha_3: None, numeric: None, name: "Sark", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "CR", alpha_3: Some("CRI"), numeric: Some("188"), name: "Costa Rica", status: Assigned, replacement: None },
+    Territory { alpha_2: "CS", alpha_3: Some("SCG"), numeric: Some("891"), name: "Serbia and Montenegro", status: TransitionallyReserved, replacement: None },
+    Territory { alpha_2: "CT", alpha_3: Some("CTE"), numeric: Some("128"), name: "Canton and Enderbury Islands", status: Withdrawn, replacement: None },
+    Territory { alpha_2: "CU", alpha_3: Some("CUB"), numeric: Some("192"), name: "Cuba", status: Assigned, replacement: None },
+    Territory { alpha_2: "CV", alpha_3: Some("CPV"), numeric: Some("132"), name: "Cabo Verde", status: Assigned, replacement: None },
+    Territory { alpha_2: "CW", alpha_3: Some("CUW"), numeric: Some("531"), name: "Curaçao", status: Assigned, replacement: None },
+    Territory { alpha_2: "CX", alpha_3: Some("CXR"), numeric: Some("162"), name: "Christmas Island", status: Assigned, replacement: None },
+    Territory { alpha_2: "CY", alpha_3: Some("CYP"), numeric: Some("196"), name: "Cyprus", status: Assigned, replacement: None },
+    Territory { alpha_2: "CZ", alpha_3: Some("CZE"), numeric: Some("203"), name: "Czechia", status: Assigned, replacement: None },
+    Territory { alpha_2: "DD", alpha_3: Some("DDR"), numeric: Some("278"), name: "German Democratic Republic", status: Withdrawn, replacement: Some("DE") },
+    Territory { alpha_2: "DE", alpha_3: Some("DEU"), numeric: Some("276"), name: "Germany", status: Assigned, replacement: None },
+    Territory { alpha_2: "DG", alpha_3: None, numeric: None, name: "Diego Garcia", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "DJ", alpha_3: Some("DJI"), numeric: Some("262"), name: "Djibouti", status: Assigned, replacement: None },
+    Territory { alpha_2: "DK", alpha_3: Some("DNK"), numeric: Some("208"), name: "Denmark", status: Assigned, replacement: None },
+    Territory { alpha_2: "DM", alpha_3: Some("DMA"), numeric: Some("212"), name: "Dominica", status: Assigned, replacement: None },
+    Territory { alpha_2: "DO", alpha_3: Some("DOM"), numeric: Some("214"), name: "Dominican Republic", status: Assigned, replacement: None },
+    Territory { alpha_2: "DY", alpha_3: Some("DHY"), numeric: Some("204"), name: "Dahomey", status: Withdrawn, replacement: Some("BJ") },
+    Territory { alpha_2: "DZ", alpha_3: Some("DZA"), numeric: Some("012"), name: "Algeria", status: Assigned, replacement: None },
+    Territory { alpha_2: "EA", alpha_3: None, numeric: None, name: "Ceuta, Melilla", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "EC", alpha_3: Some("ECU"), numeric: Some("218"), name: "Ecuador", status: Assigned, replacement: None },
+    Territory { alpha_2: "EE", alpha_3: Some("EST"), numeric: Some("233"), name: "Estonia", status: Assigned, replacement: None },
+    Territory { alpha_2: "EG", alpha_3: Some("EGY"), numeric: Some("818"), name: "Egypt", status: Assigned, replacement: None },
+    Territory { alpha_2: "EH", alpha_3: Some("ESH"), numeric: Some("732"), name: "Western Sahara", status: Assigned, replacement: None },
+    Territory { alpha_2: "ER", alpha_3: Some("ERI"), numeric: Some("232"), name: "Eritrea", status: Assigned, replacement: None },
+    Territory { alpha_2: "ES", alpha_3: Some("ESP"), numeric: Some("724"), name: "Spain", status: Assigned, replacement: None },
+    Territory { alpha_2: "ET", alpha_3: Some("ETH"), numeric: Some("231"), name: "Ethiopia", status: Assigned, replacement: None },
+    Territory { alpha_2: "EU", alpha_3: None, numeric: None, name: "European Union", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "EZ", alpha_3: None, numeric: None, name: "Eurozone", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "FI", alpha_3: Some("FIN"), numeric: Some("246"), name: "Finland", status: Assigned, replacement: None },
+    Territory { alpha_2: "FJ", alpha_3: Some("FJI"), numeric: Some("242"), name: "Fiji", status: Assigned, replacement: None },
+    Territory { alpha_2: "FK", alpha_3: Some("FLK"), numeric: Some("238"), name: "Falkland Islands (Malvinas)", status: Assigned, replacement: None },
+    Territory { alpha_2: "FM", alpha_3: Some("FSM"), numeric: Some("583"), name: "Micronesia, Federated States of", status: Assigned, replacement: None },
+    Territory { alpha_2: "FO", alpha_3: Some("FRO"), numeric: Some("234"), name: "Faroe Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "FQ", alpha_3: Some("ATF"), numeric: None, name: "French Southern and Antarctic Territories", status: Withdrawn, replacement: None },
+    Territory { alpha_2: "FR", alpha_3: Some("FRA"), numeric: Some("250"), name: "France", status: Assigned, replacement: None },
+    Territory { alpha_2: "FX", alpha_3: Some("FXX"), numeric: Some("249"), name: "France, Metropolitan", status: ExceptionallyReserved, replacement: Some("FR") },
+    Territory { alpha_2: "GA", alpha_3: Some("GAB"), numeric: Some("266"), name: "Gabon", status: Assigned, replacement: None },
+    Territory { alpha_2: "GB", alpha_3: Some("GBR"), numeric: Some("826"), name: "United Kingdom", status: Assigned, replacement: None },
+    Territory { alpha_2: "GD", alpha_3: Some("GRD"), numeric: Some("308"), name: "Grenada", status: Assigned, replacement: None },
+    Territory { alpha_2: "GE", alpha_3: Some("GEO"), numeric: Some("268"), name: "Georgia", status: Assigned, replacement: None },
+    Territory { alpha_2: "GF", alpha_3: Some("GUF"), numeric: Some("254"), name: "French Guiana", status: Assigned, replacement: None },
+    Territory { alpha_2: "GG", alpha_3: Some("GGY"), numeric: Some("831"), name: "Guernsey", status: Assigned, replacement: None },
+    Territory { alpha_2: "GH", alpha_3: Some("GHA"), numeric: Some("288"), name: "Ghana", status: Assigned, replacement: None },
+    Territory { alpha_2: "GI", alpha_3: Some("GIB"), numeric: Some("292"), name: "Gibraltar", status: Assigned, replacement: None },
+    Territory { alpha_2: "GL", alpha_3: Some("GRL"), numeric: Some("304"), name: "Greenland", status: Assigned, replacement: None },
+    Territory { alpha_2: "GM", alpha_3: Some("GMB"), numeric: Some("270"), name: "Gambia", status: Assigned, replacement: None },
+    Territory { alpha_2: "GN", alpha_3: Some("GIN"), numeric: Some("324"), name: "Guinea", status: Assigned, replacement: None },
+    Territory { alpha_2: "GP", alpha_3: Some("GLP"), numeric: Some("312"), name: "Guadeloupe", status: Assigned, replacement: None },
+    Territory { alpha_2: "GQ", alpha_3: Some("GNQ"), numeric: Some("226"), name: "Equatorial Guinea", status: Assigned, replacement: None },
+    Territory { alpha_2: "GR", alpha_3: Some("GRC"), numeric: Some("300"), name: "Greece", status: Assigned, replacement: None },
+    Territory { alpha_2: "GS", alpha_3: Some("SGS"), numeric: Some("239"), name: "South Georgia and the South Sandwich Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "GT", alpha_3: Some("GTM"), numeric: Some("320"), name: "Guatemala", status: Assigned, replacement: None },
+    Territory { alpha_2: "GU", alpha_3: Some("GUM"), numeric: Some("316"), name: "Guam", status: Assigned, replacement: None },
+    Territory { alpha_2: "GW", alpha_3: Some("GNB"), numeric: Some("624"), name: "Guinea-Bissau", status: Assigned, replacement: None },
+    Territory { alpha_2: "GY", alpha_3: Some("GUY"), numeric: Some("328"), name: "Guyana", status: Assigned, replacement: None },
+    Territory { alpha_2: "HK", alpha_3: Some("HKG"), numeric: Some("344"), name: "Hong Kong", status: Assigned, replacement: None },
+    Territory { alpha_2: "HM", alpha_3: Some("HMD"), numeric: Some("334"), name: "Heard Island and McDonald Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "HN", alpha_3: Some("HND"), numeric: Some("340"), name: "Honduras", status: Assigned, replacement: None },
+    Territory { alpha_2: "HR", alpha_3: Some("HRV"), numeric: Some("191"), name: "Croatia", status: Assigned, replacement: None },
+    Territory { alpha_2: "HT", alpha_3: Some("HTI"), numeric: Some("332"), name: "Haiti", status: Assigned, replacement: None },
+    Territory { alpha_2: "HU", alpha_3: Some("HUN"), numeric: Some("348"), name: "Hungary", status: Assigned, replacement: None },
+    Territory { alpha_2: "HV", alpha_3: Some("HVO"), numeric: Some("854"), name: "Upper Volta, Republic of", status: Withdrawn, replacement: Some("BF") },
+    Territory { alpha_2: "IC", alpha_3: None, numeric: None, name: "Canary Islands", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "ID", alpha_3: Some("IDN"), numeric: Some("360"), name: "Indonesia", status: Assigned, replacement: None },
+    Territory { alpha_2: "IE", alpha_3: Some("IRL"), numeric: Some("372"), name: "Ireland", status: Assigned, replacement: None },
+    Territory { alpha_2: "IL", alpha_3: Some("ISR"), numeric: Some("376"), name: "Israel", status: Assigned, replacement: None },
+    Territory { alpha_2: "IM", alpha_3: Some("IMN"), numeric: Some("833"), name: "Isle of Man", status: Assigned, replacement: None },
+    Territory { alpha_2: "IN", alpha_3: Some("IND"), numeric: Some("356"), name: "India", status: Assigned, replacement: None },
+    Territory { alpha_2: "IO", alpha_3: Some("IOT"), numeric: Some("086"), name: "British Indian Ocean Territory", status: Assigned, replacement: None },
+    Territory { alpha_2: "IQ", alpha_3: Some("IRQ"), numeric: Some("368"), name: "Iraq", status: Assigned, replacement: None },
+    Territory { alpha_2: "IR", alpha_3: Some("IRN"), numeric: Some("364"), name: "Iran, Islamic Republic of", status: Assigned, replacement: None },
+    Territory { alpha_2: "IS", alpha_3: Some("ISL"), numeric: Some("352"), name: "Iceland", status: Assigned, replacement: None },
+    Territory { alpha_2: "IT", alpha_3: Some("ITA"), numeric: Some("380"), name: "Italy", status: Assigned, replacement: None },
+    Territory { alpha_2: "JE", alpha_3: Some("JEY"), numeric: Some("832"), name: "Jersey", status: Assigned, replacement: None },
+    Territory { alpha_2: "JM", alpha_3: Some("JAM"), numeric: Some("388"), name: "Jamaica", status: Assigned, replacement: None },
+    Territory { alpha_2: "JO", alpha_3: Some("JOR"), numeric: Some("400"), name: "Jordan", status: Assigned, replacement: None },
+    Territory { alpha_2: "JP", alpha_3: Some("JPN"), numeric: Some("392"), name: "Japan", status: Assigned, replacement: None },
+    Territory { alpha_2: "JT", alpha_3: Some("JTN"), numeric: Some("396"), name: "Johnston Island", status: Withdrawn, replacement: None },
+    Territory { alpha_2: "KE", alpha_3: Some("KEN"), numeric: Some("404"), name: "Kenya", status: Assigned, replacement: None },
+    Territory { alpha_2: "KG", alpha_3: Some("KGZ"), numeric: Some("417"), name: "Kyrgyzstan", status: Assigned, replacement: None },
+    Territory { alpha_2: "KH", alpha_3: Some("KHM"), numeric: Some("116"), name: "Cambodia", status: Assigned, replacement: None },
+    Territory { alpha_2: "KI", alpha_3: Some("KIR"), numeric: Some("296"), name: "Kiribati", status: Assigned, replacement: None },
+    Territory { alpha_2: "KM", alpha_3: Some("COM"), numeric: Some("174"), name: "Comoros", status: Assigned, replacement: None },
+    Territory { alpha_2: "KN", alpha_3: Some("KNA"), numeric: Some("659"), name: "Saint Kitts and Nevis", status: Assigned, replacement: None },
+    Territory { alpha_2: "KP", alpha_3: Some("PRK"), numeric: Some("408"), name: "Korea, Democratic People's Republic of", status: Assigned, replacement: None },
+    Territory { alpha_2: "KR", alpha_3: Some("KOR"), numeric: Some("410"), name: "Korea, Republic of", status: Assigned, replacement: None },
+    Territory { alpha_2: "KW", alpha_3: Some("KWT"), numeric: Some("414"), name: "Kuwait", status: Assigned, replacement: None },
+    Territory { alpha_2: "KY", alpha_3: Some("CYM"), numeric: Some("136"), name: "Cayman Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "KZ", alpha_3: Some("KAZ"), numeric: Some("398"), name: "Kazakhstan", status: Assigned, replacement: None },
+    Territory { alpha_2: "LA", alpha_3: Some("LAO"), numeric: Some("418"), name: "Lao People's Democratic Republic", status: Assigned, replacement: None },
+    Territory { alpha_2: "LB", alpha_3: Some("LBN"), numeric: Some("422"), name: "Lebanon", status: Assigned, replacement: None },
+    Territory { alpha_2: "LC", alpha_3: Some("LCA"), numeric: Some("662"), name: "Saint Lucia", status: Assigned, replacement: None },
+    Territory { alpha_2: "LI", alpha_3: Some("LIE"), numeric: Some("438"), name: "Liechtenstein", status: Assigned, replacement: None },
+    Territory { alpha_2: "LK", alpha_3: Some("LKA"), numeric: Some("144"), name: "Sri Lanka", status: Assigned, replacement: None },
+    Territory { alpha_2: "LR", alpha_3: Some("LBR"), numeric: Some("430"), name: "Liberia", status: Assigned, replacement: None },
+    Territory { alpha_2: "LS", alpha_3: Some("LSO"), numeric: Some("426"), name: "Lesotho", status: Assigned, replacement: None },
+    Territory { alpha_2: "LT", alpha_3: Some("LTU"), numeric: Some("440"), name: "Lithuania", status: Assigned, replacement: None },
+    Territory { alpha_2: "LU", alpha_3: Some("LUX"), numeric: Some("442"), name: "Luxembourg", status: Assigned, replacement: None },
+    Territory { alpha_2: "LV", alpha_3: Some("LVA"), numeric: Some("428"), name: "Latvia", status: Assigned, replacement: None },
+    Territory { alpha_2: "LY", alpha_3: Some("LBY"), numeric: Some("434"), name: "Libya", status: Assigned, replacement: None },
+    Territory { alpha_2: "MA", alpha_3: Some("MAR"), numeric: Some("504"), name: "Morocco", status: Assigned, replacement: None },
+    Territory { alpha_2: "MC", alpha_3: Some("MCO"), numeric: Some("492"), name: "Monaco", status: Assigned, replacement: None },
+    Territory { alpha_2: "MD", alpha_3: Some("MDA"), numeric: Some("498"), name: "Moldova, Republic of", status: Assigned, replacement: None },
+    Territory { alpha_2: "ME", alpha_3: Some("MNE"), numeric: Some("499"), name: "Montenegro", status: Assigned, replacement: None },
+    Territory { alpha_2: "MF", alpha_3: Some("MAF"), numeric: Some("663"), name: "Saint Martin (French part)", status: Assigned, replacement: None },
+    Territory { alpha_2: "MG", alpha_3: Some("MDG"), numeric: Some("450"), name: "Madagascar", status: Assigned, replacement: None },
+    Territory { alpha_2: "MH", alpha_3: Some("MHL"), numeric: Some("584"), name: "Marshall Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "MI", alpha_3: Some("MID"), numeric: Some("488"), name: "Midway Islands", status: Withdrawn, replacement: None },
+    Territory { alpha_2: "MK", alpha_3: Some("MKD"), numeric: Some("807"), name: "North Macedonia", status: Assigned, replacement: None },
+    Territory { alpha_2: "ML", alpha_3: Some("MLI"), numeric: Some("466"), name: "Mali", status: Assigned, replacement: None },
+    Territory { alpha_2: "MM", alpha_3: Some("MMR"), numeric: Some("104"), name: "Myanmar", status: Assigned, replacement: None },
+    Territory { alpha_2: "MN", alpha_3: Some("MNG"), numeric: Some("496"), name: "Mongolia", status: Assigned, replacement: None },
+    Territory { alpha_2: "MO", alpha_3: Some("MAC"), numeric: Some("446"), name: "Macao", status: Assigned, replacement: None },
+    Territory { alpha_2: "MP", alpha_3: Some("MNP"), numeric: Some("580"), name: "Northern Mariana Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "MQ", alpha_3: Some("MTQ"), numeric: Some("474"), name: "Martinique", status: Assigned, replacement: None },
+    Territory { alpha_2: "MR", alpha_3: Some("MRT"), numeric: Some("478"), name: "Mauritania", status: Assigned, replacement: None },
+    Territory { alpha_2: "MS", alpha_3: Some("MSR"), numeric: Some("500"), name: "Montserrat", status: Assigned, replacement: None },
+    Territory { alpha_2: "MT", alpha_3: Some("MLT"), numeric: Some("470"), name: "Malta", status: Assigned, replacement: None },
+    Territory { alpha_2: "MU", alpha_3: Some("MUS"), numeric: Some("480"), name: "Mauritius", status: Assigned, replacement: None },
+    Territory { alpha_2: "MV", alpha_3: Some("MDV"), numeric: Some("462"), name: "Maldives", status: Assigned, replacement: None },
+    Territory { alpha_2: "MW", alpha_3: Some("MWI"), numeric: Some("454"), name: "Malawi", status: Assigned, replacement: None },
+    Territory { alpha_2: "MX", alpha_3: Some("MEX"), numeric: Some("484"), name: "Mexico", status: Assigned, replacement: None },
+    Territory { alpha_2: "MY", alpha_3: Some("MYS"), numeric: Some("458"), name: "Malaysia", status: Assigned, replacement: None },
+    Territory { alpha_2: "MZ", alpha_3: Some("MOZ"), numeric: Some("508"), name: "Mozambique", status: Assigned, replacement: None },
+    Territory { alpha_2: "NA", alpha_3: Some("NAM"), numeric: Some("516"), name: "Namibia", status: Assigned, replacement: None },
+    Territory { alpha_2: "NC", alpha_3: Some("NCL"), numeric: Some("540"), name: "New Caledonia", status: Assigned, replacement: None },
+    Territory { alpha_2: "NE", alpha_3: Some("NER"), numeric: Some("562"), name: "Niger", status: Assigned, replacement: None },
+    Territory { alpha_2: "NF", alpha_3: Some("NFK"), numeric: Some("574"), name: "Norfolk Island", status: Assigned, replacement: None },
+    Territory { alpha_2: "NG", alpha_3: Some("NGA"), numeric: Some("566"), name: "Nigeria", status: Assigned, replacement: None },
+    Territory { alpha_2: "NH", alpha_3: Some("NHB"), numeric: Some("548"), name: "New Hebrides", status: Withdrawn, replacement: Some("VU") },
+    Territory { alpha_2: "NI", alpha_3: Some("NIC"), numeric: Some("558"), name: "Nicaragua", status: Assigned, replacement: None },
+    Territory { alpha_2: "NL", alpha_3: Some("NLD"), numeric: Some("528"), name: "Netherlands", status: Assigned, replacement: None },
+    Territory { alpha_2: "NO", alpha_3: Some("NOR"), numeric: Some("578"), name: "Norway", status: Assigned, replacement: None },
+    Territory { alpha_2: "NP", alpha_3: Some("NPL"), numeric: Some("524"), name: "Nepal", status: Assigned, replacement: None },
+    Territory { alpha_2: "NQ", alpha_3: Some("ATN"), numeric: Some("216"), name: "Dronning Maud Land", status: Withdrawn, replacement: None },
+    Territory { alpha_2: "NR", alpha_3: Some("NRU"), numeric: Some("520"), name: "Nauru", status: Assigned, replacement: None },
+    Territory { alpha_2: "NT", alpha_3: Some("NTZ"), numeric: Some("536"), name: "Neutral Zone", status: TransitionallyReserved, replacement: None },
+    Territory { alpha_2: "NU", alpha_3: Some("NIU"), numeric: Some("570"), name: "Niue", status: Assigned, replacement: None },
+    Territory { alpha_2: "NZ", alpha_3: Some("NZL"), numeric: Some("554"), name: "New Zealand", status: Assigned, replacement: None },
+    Territory { alpha_2: "OM", alpha_3: Some("OMN"), numeric: Some("512"), name: "Oman", status: Assigned, replacement: None },
+    Territory { alpha_2: "PA", alpha_3: Some("PAN"), numeric: Some("591"), name: "Panama", status: Assigned, replacement: None },
+    Territory { alpha_2: "PC", alpha_3: Some("PCI"), numeric: Some("582"), name: "Pacific Islands (trust territory)", status: Withdrawn, replacement: None },
+    Territory { alpha_2: "PE", alpha_3: Some("PER"), numeric: Some("604"), name: "Peru", status: Assigned, replacement: None },
+    Territory { alpha_2: "PF", alpha_3: Some("PYF"), numeric: Some("258"), name: "French Polynesia", status: Assigned, replacement: None },
+    Territory { alpha_2: "PG", alpha_3: Some("PNG"), numeric: Some("598"), name: "Papua New Guinea", status: Assigned, replacement: None },
+    Territory { alpha_2: "PH", alpha_3: Some("PHL"), numeric: Some("608"), name: "Philippines", status: Assigned, replacement: None },
+    Territory { alpha_2: "PK", alpha_3: Some("PAK"), numeric: Some("586"), name: "Pakistan", status: Assigned, replacement: None },
+    Territory { alpha_2: "PL", alpha_3: Some("POL"), numeric: Some("616"), name: "Poland", status: Assigned, replacement: None },
+    Territory { alpha_2: "PM", alpha_3: Some("SPM"), numeric: Some("666"), name: "Saint Pierre and Miquelon", status: Assigned, replacement: None },
+    Territory { alpha_2: "PN", alpha_3: Some("PCN"), numeric: Some("612"), name: "Pitcairn", status: Assigned, replacement: None },
+    Territory { alpha_2: "PR", alpha_3: Some("PRI"), numeric: Some("630"), name: "Puerto Rico", status: Assigned, replacement: None },
+    Territory { alpha_2: "PS", alpha_3: Some("PSE"), numeric: Some("275"), name: "Palestine, State of", status: Assigned, replacement: None },
+    Territory { alpha_2: "PT", alpha_3: Some("PRT"), numeric: Some("620"), name: "Portugal", status: Assigned, replacement: None },
+    Territory { alpha_2: "PU", alpha_3: Some("PUS"), numeric: Some("849"), name: "US Miscellaneous Pacific Islands", status: Withdrawn, replacement: None },
+    Territory { alpha_2: "PW", alpha_3: Some("PLW"), numeric: Some("585"), name: "Palau", status: Assigned, replacement: None },
+    Territory { alpha_2: "PY", alpha_3: Some("PRY"), numeric: Some("600"), name: "Paraguay", status: Assigned, replacement: None },
+    Territory { alpha_2: "PZ", alpha_3: Some("PCZ"), numeric: None, name: "Panama Canal Zone", status: Withdrawn, replacement: None },
+    Territory { alpha_2: "QA", alpha_3: Some("QAT"), numeric: Some("634"), name: "Qatar", status: Assigned, replacement: None },
+    Territory { alpha_2: "RE", alpha_3: Some("REU"), numeric: Some("638"), name: "Réunion", status: Assigned, replacement: None },
+    Territory { alpha_2: "RH", alpha_3: Some("RHO"), numeric: Some("716"), name: "Southern Rhodesia", status: Withdrawn, replacement: Some("ZW") },
+    Territory { alpha_2: "RO", alpha_3: Some("ROU"), numeric: Some("642"), name: "Romania", status: Assigned, replacement: None },
+    Territory { alpha_2: "RS", alpha_3: Some("SRB"), numeric: Some("688"), name: "Serbia", status: Assigned, replacement: None },
+    Territory { alpha_2: "RU", alpha_3: Some("RUS"), numeric: Some("643"), name: "Russian Federation", status: Assigned, replacement: None },
+    Territory { alpha_2: "RW", alpha_3: Some("RWA"), numeric: Some("646"), name: "Rwanda", status: Assigned, replacement: None },
+    Territory { alpha_2: "SA", alpha_3: Some("SAU"), numeric: Some("682"), name: "Saudi Arabia", status: Assigned, replacement: None },
+    Territory { alpha_2: "SB", alpha_3: Some("SLB"), numeric: Some("090"), name: "Solomon Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "SC", alpha_3: Some("SYC"), numeric: Some("690"), name: "Seychelles", status: Assigned, replacement: None },
+    Territory { alpha_2: "SD", alpha_3: Some("SDN"), numeric: Some("729"), name: "Sudan", status: Assigned, replacement: None },
+    Territory { alpha_2: "SE", alpha_3: Some("SWE"), numeric: Some("752"), name: "Sweden", status: Assigned, replacement: None },
+    Territory { alpha_2: "SF", alpha_3: None, numeric: None, name: "Finland", status: TransitionallyReserved, replacement: Some("FI") },
+    Territory { alpha_2: "SG", alpha_3: Some("SGP"), numeric: Some("702"), name: "Singapore", status: Assigned, replacement: None },
+    Territory { alpha_2: "SH", alpha_3: Some("SHN"), numeric: Some("654"), name: "Saint Helena, Ascension and Tristan da Cunha", status: Assigned, replacement: None },
+    Territory { alpha_2: "SI", alpha_3: Some("SVN"), numeric: Some("705"), name: "Slovenia", status: Assigned, replacement: None },
+    Territory { alpha_2: "SJ", alpha_3: Some("SJM"), numeric: Some("744"), name: "Svalbard and Jan Mayen", status: Assigned, replacement: None },
+    Territory { alpha_2: "SK", alpha_3: Some("SVK"), numeric: Some("703"), name: "Slovakia", status: Assigned, replacement: None },
+    Territory { alpha_2: "SL", alpha_3: Some("SLE"), numeric: Some("694"), name: "Sierra Leone", status: Assigned, replacement: None },
+    Territory { alpha_2: "SM", alpha_3: Some("SMR"), numeric: Some("674"), name: "San Marino", status: Assigned, replacement: None },
+    Territory { alpha_2: "SN", alpha_3: Some("SEN"), numeric: Some("686"), name: "Senegal", status: Assigned, replacement: None },
+    Territory { alpha_2: "SO", alpha_3: Some("SOM"), numeric: Some("706"), name: "Somalia", status: Assigned, replacement: None },
+    Territory { alpha_2: "SR", alpha_3: Some("SUR"), numeric: Some("740"), name: "Suriname", status: Assigned, replacement: None },
+    Territory { alpha_2: "SS", alpha_3: Some("SSD"), numeric: Some("728"), name: "South Sudan", status: Assigned, replacement: None },
+    Territory { alpha_2: "ST", alpha_3: Some("STP"), numeric: Some("678"), name: "Sao Tome and Principe", status: Assigned, replacement: None },
+    Territory { alpha_2: "SU", alpha_3: Some("SUN"), numeric: Some("810"), name: "USSR, Union of Soviet Socialist Republics", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "SV", alpha_3: Some("SLV"), numeric: Some("222"), name: "El Salvador", status: Assigned, replacement: None },
+    Territory { alpha_2: "SX", alpha_3: Some("SXM"), numeric: Some("534"), name: "Sint Maarten (Dutch part)", status: Assigned, replacement: None },
+    Territory { alpha_2: "SY", alpha_3: Some("SYR"), numeric: Some("760"), name: "Syrian Arab Republic", status: Assigned, replacement: None },
+    Territory { alpha_2: "SZ", alpha_3: Some("SWZ"), numeric: Some("748"), name: "Eswatini", status: Assigned, replacement: None },
+    Territory { alpha_2: "TA", alpha_3: None, numeric: None, name: "Tristan da Cunha", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "TC", alpha_3: Some("TCA"), numeric: Some("796"), name: "Turks and Caicos Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "TD", alpha_3: Some("TCD"), numeric: Some("148"), name: "Chad", status: Assigned, replacement: None },
+    Territory { alpha_2: "TF", alpha_3: Some("ATF"), numeric: Some("260"), name: "French Southern Territories", status: Assigned, replacement: None },
+    Territory { alpha_2: "TG", alpha_3: Some("TGO"), numeric: Some("768"), name: "Togo", status: Assigned, replacement: None },
+    Territory { alpha_2: "TH", alpha_3: Some("THA"), numeric: Some("764"), name: "Thailand", status: Assigned, replacement: None },
+    Territory { alpha_2: "TJ", alpha_3: Some("TJK"), numeric: Some("762"), name: "Tajikistan", status: Assigned, replacement: None },
+    Territory { alpha_2: "TK", alpha_3: Some("TKL"), numeric: Some("772"), name: "Tokelau", status: Assigned, replacement: None },
+    Territory { alpha_2: "TL", alpha_3: Some("TLS"), numeric: Some("626"), name: "Timor-Leste", status: Assigned, replacement: None },
+    Territory { alpha_2: "TM", alpha_3: Some("TKM"), numeric: Some("795"), name: "Turkmenistan", status: Assigned, replacement: None },
+    Territory { alpha_2: "TN", alpha_3: Some("TUN"), numeric: Some("788"), name: "Tunisia", status: Assigned, replacement: None },
+    Territory { alpha_2: "TO", alpha_3: Some("TON"), numeric: Some("776"), name: "Tonga", status: Assigned, replacement: None },
+    Territory { alpha_2: "TP", alpha_3: Some("TMP"), numeric: Some("626"), name: "East Timor", status: TransitionallyReserved, replacement: Some("TL") },
+    Territory { alpha_2: "TR", alpha_3: Some("TUR"), numeric: Some("792"), name: "Türkiye", status: Assigned, replacement: None },
+    Territory { alpha_2: "TT", alpha_3: Some("TTO"), numeric: Some("780"), name: "Trinidad and Tobago", status: Assigned, replacement: None },
+    Territory { alpha_2: "TV", alpha_3: Some("TUV"), numeric: Some("798"), name: "Tuvalu", status: Assigned, replacement: None },
+    Territory { alpha_2: "TW", alpha_3: Some("TWN"), numeric: Some("158"), name: "Taiwan, Province of China", status: Assigned, replacement: None },
+    Territory { alpha_2: "TZ", alpha_3: Some("TZA"), numeric: Some("834"), name: "Tanzania, United Republic of", status: Assigned, replacement: None },
+    Territory { alpha_2: "UA", alpha_3: Some("UKR"), numeric: Some("804"), name: "Ukraine", status: Assigned, replacement: None },
+    Territory { alpha_2: "UG", alpha_3: Some("UGA"), numeric: Some("800"), name: "Uganda", status: Assigned, replacement: None },
+    Territory { alpha_2: "UK", alpha_3: None, numeric: None, name: "United Kingdom", status: ExceptionallyReserved, replacement: Some("GB") },
+    Territory { alpha_2: "UM", alpha_3: Some("UMI"), numeric: Some("581"), name: "United States Minor Outlying Islands", status: Assigned, replacement: None },
+    Territory { alpha_2: "UN", alpha_3: None, numeric: None, name: "United Nations", status: ExceptionallyReserved, replacement: None },
+    Territory { alpha_2: "US", alpha_3: Some("USA"), numeric: Some("840"), name: "United States", status: Assigned, replacement: None },
+    Territory { alpha_2: "UY", alpha_3: Some("URY"), numeric: Some("858"), name: "Uruguay", status: Assigned, replacement: None },
+    Territory { alpha_2: "UZ", alpha_3: Some("UZB"), numeric: Some("860"), name: "Uzbekistan", status: Assigned, replacement: None },
+    Territory { alpha_2: "VA", alpha_3: Some("VAT"), numeric: Some("336"), name: "Holy See (Vatican City State)", status: Assigned, replacement: None },
+    Territory { alpha_2: "VC", alpha_3: Some("VCT"), numeric: Some("670"), name: "Saint Vincent and the Grenadines", status: Assigned, replacement: None },
+    Territory { alpha_2: "VD", alpha_3: Some("VDR"), numeric: None, name: "Viet-Nam, Democratic Republic of", status: Withdrawn, replacement: Some("VN") },
+    Territory { alpha_2: "VE", alpha_3: Some("VEN"), numeric: Some("862"), name: "Venezuela, Bolivarian Republic of", status: Assigned, replacement: None },
+    Territory { alpha_2: "VG", alpha_3: Some("VGB"), numeric: Some("092"), name: "Virgin Islands, British", status: Assigned, replacement: None },
+    Territory { alpha_2: "VI", alpha_3: Some("VIR"), numeric: Some("850"), name: "Virgin Islands, U.S.", status: Assigned, replacement: None },
+    Territory { alpha_2: "VN", alpha_3: Some("VNM"), numeric: Some("704"), name: "Viet Nam", status: Assigned, replacement: None },
+    Territory { alpha_2: "VU", alpha_3: Some("VUT"), numeric: Some("548"), name: "Vanuatu", status: Assigned, replacement: None },
+    Territory { alpha_2: "WF", alpha_3: Some("WLF"), numeric: Some("876"), name: "Wallis and Futuna", status: Assigned, replacement: None },
+    Territory { alpha_2: "WK", alpha_3: Some("WAK"), numeric: Some("872"), name: "Wake Island", status: Withdrawn, replacement: None },
+    Territory { alpha_2: "WS", alpha_3: Some("WSM"), numeric: Some("882"), name: "Samoa", status: Assigned, replacement: None },
+    Territory { alpha_2: "YD", alpha_3: Some("YMD"), numeric: Some("720"), name: "Yemen, Democratic, People's Democratic Republic of", status: Withdrawn, replacement: Some("YE") },
+    Territory { alpha_2: "YE", alpha_3: Some("YEM"), numeric: Some("887"), name: "Yemen", status: Assigned, replacement: None },
+    Territory { alpha_2: "YT", alpha_3: Some("MYT"), numeric: Some("175"), name: "Mayotte", status: Assigned, replacement: None },
+    Territory { alpha_2: "YU", alpha_3: Some("YUG"), numeric: Some("891"), name: "Yugoslavia, (Socialist) Federal Republic of", status: TransitionallyReserved, replacement: None },
+    Territory { alpha_2: "ZA", alpha_3: Some("ZAF"), numeric: Some("710"), name: "South Africa", status: Assigned, replacement: None },
+    Territory { alpha_2: "ZM", alpha_3: Some("ZMB"), numeric: Some("894"), name: "Zambia", status: Assigned, replacement: None },
+    Territory { alpha_2: "ZR", alpha_3: Some("ZAR"), numeric: Some("180"), name: "Zaire, Republic of", status: TransitionallyReserved, replacement: Some("CD") },
+    Territory { alpha_2: "ZW", alpha_3: Some("ZWE"), numeric: Some("716"), name: "Zimbabwe", status: Assigned, replacement: None },
+];
@@ -0,0 +1,432 @@
+/*!
+Provides the `LocaleSettings` type, which holds the locale of each category, and its
+composite string forms.
+
+## Standards
+
+When the categories of a process have different locales, `setlocale(LC_ALL, NULL)`
+returns a composite string rather than a single locale. Two forms are in common use:
+
+* The GNU C library lists each category by name, separated by `;`, for example
+  `LC_CTYPE=en_US.UTF-8;LC_NUMERIC=de_DE.UTF-8;...;LC_IDENTIFICATION=en_US.UTF-8`.
+* The BSD C libraries, including macOS, list the locales of the categories
+  `LC_COLLATE`, `LC_CTYPE`, `LC_MONETARY`, `LC_NUMERIC`, `LC_TIME`, and `LC_MESSAGES`,
+  in that order, separated by `/`, for example `C/en_US.UTF-8/C/C/C/C`.
+
+When all categories have the same locale both forms are simply that locale.
+
+## Examples
+
+```
+use locale_types::{Locale, LocaleCategory, LocaleSettings};
+use std::str::FromStr;
+
+let mut settings = LocaleSettings::from_str("en_US.UTF-8").unwrap();
+assert_eq!(settings.to_string(), "en_US.UTF-8");
+
+settings.set(LocaleCategory::Numeric, Locale::from_str("de_DE.UTF-8").unwrap());
+assert_eq!(settings.collapse(), None);
+assert_eq!(
+    settings.get(LocaleCategory::Numeric).unwrap().to_string(),
+    "de_DE.UTF-8"
+);
+assert!(settings
+    .to_string()
+    .starts_with("LC_CTYPE=en_US.UTF-8;LC_NUMERIC=de_DE.UTF-8;LC_TIME=en_US.UTF-8;"));
+
+let settings = LocaleSettings::from_str("C/en_US.UTF-8/C/C/C/C").unwrap();
+assert_eq!(
+    settings.get(LocaleCategory::CharacterType).unwrap().to_string(),
+    "en_US.UTF-8"
+);
+assert_eq!(settings.to_bsd_string().unwrap(), "C/en_US.UTF-8/C/C/C/C");
+```
+*/
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::category::LocaleCategory;
+use crate::env::{resolve_all, Environment};
+use crate::locale::Locale;
+use crate::{LocaleError, LocaleResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The locale of each individual category; a category may also be unspecified.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LocaleSettings {
+    categories: BTreeMap<LocaleCategory, Locale>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - LocaleSettings
+// ------------------------------------------------------------------------------------------------
+
+const SEP_GNU_CATEGORY: char = ';';
+const SEP_GNU_VALUE: char = '=';
+const SEP_BSD_CATEGORY: char = '/';
+
+impl LocaleSettings {
+    /// Construct new settings, with no category specified.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Construct new settings where every individual category has the locale `locale`.
+    pub fn uniform(locale: Locale) -> Self {
+        let mut settings = LocaleSettings::new();
+        settings.set(LocaleCategory::All, locale);
+        settings
+    }
+
+    /// Construct new settings from the effective locale of each category in the
+    /// environment `env`, see `env::resolve_all`.
+    pub fn from_environment<E: Environment>(env: &E) -> LocaleResult<Self> {
+        Ok(LocaleSettings {
+            categories: resolve_all(env)?
+                .into_iter()
+                .map(|effective| (effective.category(), effective.into_locale()))
+                .collect(),
+        })
+    }
+
+    /// Return the locale of `category`, or `None` if it is not specified. For
+    /// `LocaleCategory::All` this is the same as `collapse`.
+    pub fn get(&self, category: LocaleCategory) -> Option<&Locale> {
+        if category == LocaleCategory::All {
+            self.collapsed()
+        } else {
+            self.categories.get(&category)
+        }
+    }
+
+    /// Set the locale of `category`; for `LocaleCategory::All` every individual
+    /// category is set.
+    pub fn set(&mut self, category: LocaleCategory, locale: Locale) {
+        if category == LocaleCategory::All {
+            for category in LocaleCategory::individual() {
+                let _ = self.categories.insert(*category, locale.clone());
+            }
+        } else {
+            let _ = self.categories.insert(category, locale);
+        }
+    }
+
+    /// Remove the locale of `category`, returning it if it was specified; for
+    /// `LocaleCategory::All` every category is removed and `None` is returned.
+    pub fn remove(&mut self, category: LocaleCategory) -> Option<Locale> {
+        if category == LocaleCategory::All {
+            self.categories.clear();
+            None
+        } else {
+            self.categories.remove(&category)
+        }
+    }
+
+    /// Return the specified categories, and their locales, in category order.
+    pub fn categories(&self) -> Vec<(LocaleCategory, Locale)> {
+        self.categories
+            .iter()
+            .map(|(category, locale)| (*category, locale.clone()))
+            .collect()
+    }
+
+    /// Returns `true` if no category is specified.
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty()
+    }
+
+    /// Return the single locale of all specified categories, or `None` if no
+    /// category is specified or the categories have different locales.
+    pub fn collapse(&self) -> Option<Locale> {
+        self.collapsed().cloned()
+    }
+
+    /// Return the GNU composite form, listing every specified category even when all
+    /// have the same locale.
+    pub fn to_gnu_string(&self) -> String {
+        let entries: Vec<String> = self
+            .categories
+            .iter()
+            .map(|(category, locale)| format!("{}{}{}", category, SEP_GNU_VALUE, locale))
+            .collect();
+        entries.join(&SEP_GNU_CATEGORY.to_string())
+    }
+
+    /// Return the BSD composite form, or `None` if any of the categories in this form
+    /// are not specified, or the locale of any is a path.
+    pub fn to_bsd_string(&self) -> Option<String> {
+        let mut entries: Vec<String> = Vec::new();
+        for category in BSD_CATEGORIES.iter() {
+            match self.categories.get(category) {
+                Some(Locale::Path(_)) | None => return None,
+                Some(locale) => entries.push(locale.to_string()),
+            }
+        }
+        Some(entries.join(&SEP_BSD_CATEGORY.to_string()))
+    }
+
+    fn collapsed(&self) -> Option<&Locale> {
+        let mut locales = self.categories.values();
+        let first = locales.next()?;
+        if locales.all(|locale| locale == first) {
+            Some(first)
+        } else {
+            None
+        }
+    }
+}
+
+/// Formats as the single locale of all categories if they agree, otherwise in the
+/// GNU composite form.
+impl Display for LocaleSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.collapsed() {
+            Some(locale) => write!(f, "{}", locale),
+            None => write!(f, "{}", self.to_gnu_string()),
+        }
+    }
+}
+
+/// Parses a single locale, which is applied to all categories, or either composite
+/// form. A string is only parsed in the GNU form if it starts with a category name
+/// and `=`, and entries are only separated by a `;` followed by another `LC_` name,
+/// so that a modifier such as `@collation=pinyin;currency=CNY` is kept whole. The
+/// categories are matched as by `LocaleCategory::from_str`, categories not listed
+/// are unspecified, and `LC_ALL` or a repeated category is reported as
+/// `LocaleError::InvalidLocaleString`. A string is only parsed in the BSD form if it
+/// does not start with `/`, and so is not a path, and has exactly one value for each
+/// of the categories in that form.
+impl FromStr for LocaleSettings {
+    type Err = LocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut settings = LocaleSettings::new();
+        if is_gnu_composite(s) {
+            for entry in gnu_entries(s).into_iter().filter(|entry| !entry.is_empty()) {
+                let mut parts = entry.splitn(2, SEP_GNU_VALUE);
+                let category = LocaleCategory::from_str(parts.next().unwrap())?;
+                let locale = parts.next().ok_or(LocaleError::InvalidLocaleString)?;
+                if category == LocaleCategory::All || settings.categories.contains_key(&category) {
+                    return Err(LocaleError::InvalidLocaleString);
+                }
+                settings.set(category, Locale::from_str(locale)?);
+            }
+        } else if !s.starts_with(SEP_BSD_CATEGORY)
+            && s.matches(SEP_BSD_CATEGORY).count() == BSD_CATEGORIES.len() - 1
+        {
+            for (category, locale) in BSD_CATEGORIES.iter().zip(s.split(SEP_BSD_CATEGORY)) {
+                settings.set(*category, Locale::from_str(locale)?);
+            }
+        } else {
+            settings.set(LocaleCategory::All, Locale::from_str(s)?);
+        }
+        if settings.is_empty() {
+            Err(LocaleError::InvalidLocaleString)
+        } else {
+            Ok(settings)
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Returns `true` if `s` starts with a category name followed by `=`.
+fn is_gnu_composite(s: &str) -> bool {
+    match s.find(SEP_GNU_VALUE) {
+        Some(index) => LocaleCategory::from_str(&s[..index]).is_ok(),
+        None => false,
+    }
+}
+
+/// Split `s` at each `;` that is followed by an `LC_` name, in any case.
+fn gnu_entries(s: &str) -> Vec<&str> {
+    let mut entries: Vec<&str> = Vec::new();
+    let mut start = 0;
+    for (index, _) in s.match_indices(SEP_GNU_CATEGORY) {
+        let rest = &s[index + SEP_GNU_CATEGORY.len_utf8()..];
+        if rest.is_empty()
+            || rest
+                .get(..GNU_PREFIX.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(GNU_PREFIX))
+        {
+            entries.push(&s[start..index]);
+            start = index + SEP_GNU_CATEGORY.len_utf8();
+        }
+    }
+    entries.push(&s[start..]);
+    entries
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+/// The prefix of every category name in the GNU composite form.
+const GNU_PREFIX: &str = "LC_";
+
+/// The categories, in order, of the BSD composite form.
+const BSD_CATEGORIES: [LocaleCategory; 6] = [
+    LocaleCategory::Collate,
+    LocaleCategory::CharacterType,
+    LocaleCategory::Monetary,
+    LocaleCategory::Numeric,
+    LocaleCategory::Time,
+    LocaleCategory::Messages,
+];
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use super::*;
+
+    const GLIBC_COMPOSITE: &str = "LC_CTYPE=en_US.UTF-8;LC_NUMERIC=de_DE.UTF-8;\
+        LC_TIME=en_US.UTF-8;LC_COLLATE=en_US.UTF-8;LC_MONETARY=en_US.UTF-8;\
+        LC_MESSAGES=en_US.UTF-8;LC_PAPER=en_US.UTF-8;LC_NAME=en_US.UTF-8;\
+        LC_ADDRESS=en_US.UTF-8;LC_TELEPHONE=en_US.UTF-8;LC_MEASUREMENT=en_US.UTF-8;\
+        LC_IDENTIFICATION=en_US.UTF-8";
+
+    fn locale(s: &str) -> Locale {
+        Locale::from_str(s).unwrap()
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_gnu_round_trip() {
+        let mut settings = LocaleSettings::from_str(GLIBC_COMPOSITE).unwrap();
+        assert_eq!(settings.categories().len(), 12);
+        assert_eq!(
+            settings.get(LocaleCategory::Numeric),
+            Some(&locale("de_DE.UTF-8"))
+        );
+        assert_eq!(settings.get(LocaleCategory::All), None);
+        assert_eq!(settings.to_string(), GLIBC_COMPOSITE);
+
+        settings.set(LocaleCategory::Numeric, locale("en_US.UTF-8"));
+        assert_eq!(settings.collapse(), Some(locale("en_US.UTF-8")));
+        assert_eq!(settings.to_string(), "en_US.UTF-8");
+        assert_eq!(settings.to_gnu_string().matches(';').count(), 11);
+    }
+
+    #[test]
+    fn test_gnu_partial() {
+        let settings = LocaleSettings::from_str("LC_TIME=en_GB;lc_paper=C;").unwrap();
+        assert_eq!(settings.categories().len(), 2);
+        assert_eq!(settings.get(LocaleCategory::Paper), Some(&Locale::posix()));
+        assert_eq!(settings.get(LocaleCategory::Messages), None);
+        assert_eq!(settings.to_string(), "LC_TIME=en_GB;LC_PAPER=C");
+        assert_eq!(settings.to_bsd_string(), None);
+    }
+
+    #[test]
+    fn test_gnu_errors() {
+        for (s, error) in &[
+            ("LC_ALL=C", LocaleError::InvalidLocaleString),
+            ("LC_TIME=C;LC_TIME=C", LocaleError::InvalidLocaleString),
+            ("LC_TIME=C;lc_time=C", LocaleError::InvalidLocaleString),
+        ] {
+            assert_eq!(
+                LocaleSettings::from_str(s).err().as_ref(),
+                Some(error),
+                "{}",
+                s
+            );
+        }
+        for s in &[
+            "LC_TIME=",
+            "LC_TIME",
+            ";;",
+            "LC_COLOR=C",
+            "éé=C",
+            "LC_TIME=C;é=C",
+        ] {
+            match LocaleSettings::from_str(s) {
+                Err(LocaleError::Parse(_)) => (),
+                result => panic!("expected a parse error, not {:?}", result),
+            }
+        }
+    }
+
+    #[test]
+    fn test_gnu_modifier_separators() {
+        let s = "en_US@collation=pinyin;currency=CNY";
+        let settings = LocaleSettings::from_str(s).unwrap();
+        assert_eq!(settings.categories().len(), 12);
+        assert_eq!(settings.collapse(), Some(locale(s)));
+        assert_eq!(settings.to_string(), s);
+
+        let s = "LC_TIME=C;LC_COLLATE=en_US@collation=pinyin;currency=CNY";
+        let settings = LocaleSettings::from_str(s).unwrap();
+        assert_eq!(settings.categories().len(), 2);
+        assert_eq!(
+            settings.get(LocaleCategory::Collate),
+            Some(&locale("en_US@collation=pinyin;currency=CNY"))
+        );
+        assert_eq!(settings.to_string(), s);
+    }
+
+    #[test]
+    fn test_bsd_round_trip() {
+        let settings = LocaleSettings::from_str("C/en_US.UTF-8/C/C/fr_FR/C").unwrap();
+        assert_eq!(settings.categories().len(), 6);
+        assert_eq!(settings.get(LocaleCategory::Time), Some(&locale("fr_FR")));
+        assert_eq!(
+            settings.get(LocaleCategory::Collate),
+            Some(&Locale::posix())
+        );
+        assert_eq!(settings.get(LocaleCategory::Paper), None);
+        assert_eq!(
+            settings.to_bsd_string().unwrap(),
+            "C/en_US.UTF-8/C/C/fr_FR/C"
+        );
+        let mut settings = LocaleSettings::new();
+        settings.set(LocaleCategory::All, Locale::posix());
+        settings.set(LocaleCategory::CharacterType, locale("en_US.UTF-8"));
+        assert_eq!(settings.to_bsd_string().unwrap(), "C/en_US.UTF-8/C/C/C/C");
+        let settings = LocaleSettings::from_str("POSIX/en_US.UTF-8/C/C/POSIX/C").unwrap();
+        assert_eq!(settings.to_bsd_string().unwrap(), "C/en_US.UTF-8/C/C/C/C");
+        let settings = LocaleSettings::from_str("de_DE/de_DE/de_DE/de_DE/de_DE/de_DE").unwrap();
+        assert_eq!(settings.to_string(), "de_DE");
+    }
+
+    #[test]
+    fn test_single() {
+        let settings = LocaleSettings::from_str("/usr/share/locale/en_US").unwrap();
+        assert_eq!(settings.categories().len(), 12);
+        assert_eq!(settings.collapse(), Some(locale("/usr/share/locale/en_US")));
+        assert_eq!(settings.to_bsd_string(), None);
+        assert!(LocaleSettings::from_str("").is_err());
+        assert!(LocaleSettings::new().collapse().is_none());
+    }
+
+    #[test]
+    fn test_from_environment() {
+        let mut env = HashMap::new();
+        let _ = env.insert("LANG", "en_US.UTF-8");
+        let _ = env.insert("LC_MONETARY", "en_GB.UTF-8");
+        let mut settings = LocaleSettings::from_environment(&env).unwrap();
+        assert_eq!(
+            settings.get(LocaleCategory::Monetary),
+            Some(&locale("en_GB.UTF-8"))
+        );
+        assert!(settings.to_string().contains(";LC_MONETARY=en_GB.UTF-8;"));
+        assert_eq!(
+            settings.remove(LocaleCategory::Monetary),
+            Some(locale("en_GB.UTF-8"))
+        );
+        assert_eq!(settings.to_string(), "en_US.UTF-8");
+    }
+}
@@ -12,16 +12,20 @@ Locale identifiers are defined in this format: `[language[_territory][.codeset][
 For example, Australian English using the UTF-8 encoding is `en_AU.UTF-8`.
 
 * `language` = [ISO 639-1](https://en.wikipedia.org/wiki/ISO_639-1) 2-character language
-  codes.
+  codes, or [ISO 639-2](https://en.wikipedia.org/wiki/ISO_639-2) and
+  [ISO 639-3](https://en.wikipedia.org/wiki/ISO_639-3) 3-character language codes where no
+  2-character code exists, for example `ast_ES` or `fil_PH`.
 * `territory` = [ISO 3166-1](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) 2-character
-  country codes.
-* `codeset` = an undefined string value, `[a-zA-Z0-9_\-]+`.
+  country codes, or [UN M.49](https://unstats.un.org/unsd/methodology/m49/) 3-digit region
+  codes, for example `es_419` for Latin American Spanish.
+* `codeset` = an undefined string value, `[a-zA-Z0-9][a-zA-Z0-9_\-.:]*`.
   * For example, [IEC 8859](https://en.wikipedia.org/wiki/ISO/IEC_8859) parts 1 to 16 are
     usually specified as `ISO8859-1` and so on.
   * should be taken from the values in the IANA
     [character sets](https://www.iana.org/assignments/character-sets/character-sets.xhtml)
     list.
-* `modifier` = a semi-colon separated list of _identifiers_, or _name '=' value_ pairs.
+* `modifier` = a semi-colon separated list of _identifiers_, or _name '=' value_ pairs,
+  represented in this crate by the [`Modifier`](../modifier/struct.Modifier.html) type.
   * Sometimes this is used to indicate the language script in use, as such values from
     [ISO 15924](http://unicode.org/iso15924/iso15924-codes.html) should be used.
 
@@ -38,14 +42,14 @@ See also:
 * [ISO _Procedures for the registration of cultural elements_](https://www.iso.org/standard/50707.html)
 
 */
-use std::collections::HashMap;
+use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
+use std::ops::Range;
 use std::str::FromStr;
 
-use regex::Regex;
-
-use crate::id::LocaleIdentifier;
+use crate::id::{LocaleIdentifier, ModifierOrder, ModifierPair};
+use crate::modifier::Modifier;
 use crate::{LocaleError, LocaleResult};
 
 // ------------------------------------------------------------------------------------------------
@@ -55,23 +59,67 @@ use crate::{LocaleError, LocaleResult};
 /// A `LocaleString` is a representation of the POSIX notion of a Locale
 /// identifier, used in operating system calls and environment variables.
 /// It implements the `LocaleIdentifier` trait.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LocaleString {
     language_code: String,
     territory: Option<String>,
     code_set: Option<String>,
-    modifier: Option<String>,
+    modifier: Option<Modifier>,
+}
+
+/// Distinguishes territories that are individual countries, or areas, from
+/// macro-regions that group several countries together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TerritoryKind {
+    /// An ISO 3166-1 alpha-2 country code, such as `US`, or a UN M.49 numeric
+    /// code for a country or area, such as `840`.
+    Country,
+    /// A UN M.49 numeric code for a macro-region, such as `001` (World) or
+    /// `419` (Latin America and the Caribbean).
+    MacroRegion,
 }
 
-/// Errors possibly returned from `from_str()`.
-#[derive(Debug, PartialEq)]
-pub enum ParseError {
+/// Errors possibly returned from `from_str()`, identifying the kind of error,
+/// the string being parsed, and the span of bytes within it at which the error
+/// was detected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    input: String,
+    span: Range<usize>,
+}
+
+/// A component of an identifier, such as the one that failed to parse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Component {
+    /// The language code, or BCP 47 language and extended language subtags.
+    Language,
+    /// The territory code, or BCP 47 region subtag.
+    Territory,
+    /// The code set name.
+    CodeSet,
+    /// The modifier, or BCP 47 script subtag.
+    Modifier,
+    /// A BCP 47 variant subtag.
+    Variant,
+    /// A BCP 47 extension.
+    Extension,
+    /// BCP 47 private use subtags.
+    PrivateUse,
+    /// A file system path.
+    Path,
+    /// A subtag of an RFC 4647 language range.
+    LanguageRange,
+}
+
+/// The kind of error, and so the component of the identifier that failed to parse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseErrorKind {
     /// The empty string is not a valid identifier.
     EmptyString,
-    /// The value "POSIX" or "C" is not a locale identifier in this context.
+    /// The value "POSIX" or "C", with or without a code set or modifier, is not a
+    /// locale identifier in this context.
     PosixUnsupported,
-    /// The string failed to match the internal regular expression(s).
-    RegexFailure,
     /// The provided language code was not valid.
     InvalidLanguageCode,
     /// The provided territory code was not valid.
@@ -80,8 +128,16 @@ pub enum ParseError {
     InvalidCodeSet,
     /// The provided modifier string was not valid.
     InvalidModifier,
+    /// The provided variant subtag was not valid, or was repeated.
+    InvalidVariant,
+    /// The provided extension was not valid, or was repeated.
+    InvalidExtension,
+    /// The provided private use subtags were not valid.
+    InvalidPrivateUse,
     /// The provided file system path was not valid.
     InvalidPath,
+    /// The provided language range subtag was not valid.
+    InvalidLanguageRange,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -92,9 +148,169 @@ const SEP_TERRITORY: char = '_';
 const SEP_CODE_SET: char = '.';
 const SEP_MODIFIER: char = '@';
 
+/// glibc modifier names that denote a script, and the corresponding ISO 15924 code.
+pub(crate) const SCRIPT_MODIFIERS: [(&str, &str); 6] = [
+    ("latin", "Latn"),
+    ("cyrillic", "Cyrl"),
+    ("devanagari", "Deva"),
+    ("arabic", "Arab"),
+    ("hebrew", "Hebr"),
+    ("iqtelif", "Latn"),
+];
+
+/// The display form of the code sets used by the locales supported by glibc, taken
+/// from the names of the glibc character maps.
+const DISPLAY_CODE_SETS: [&str; 38] = [
+    "ANSI_X3.4-1968",
+    "ARMSCII-8",
+    "BIG5",
+    "BIG5-HKSCS",
+    "CP1251",
+    "CP1255",
+    "EUC-JP",
+    "EUC-KR",
+    "EUC-TW",
+    "GB18030",
+    "GB2312",
+    "GBK",
+    "GEORGIAN-PS",
+    "ISO-8859-1",
+    "ISO-8859-2",
+    "ISO-8859-3",
+    "ISO-8859-4",
+    "ISO-8859-5",
+    "ISO-8859-6",
+    "ISO-8859-7",
+    "ISO-8859-8",
+    "ISO-8859-9",
+    "ISO-8859-10",
+    "ISO-8859-11",
+    "ISO-8859-13",
+    "ISO-8859-14",
+    "ISO-8859-15",
+    "ISO-8859-16",
+    "KOI8-R",
+    "KOI8-T",
+    "KOI8-U",
+    "PT154",
+    "RK1048",
+    "TCVN5712-1",
+    "TIS-620",
+    "UTF-8",
+    "UTF-16",
+    "UTF-32",
+];
+
+impl LocaleString {
+    /// Return a new identifier based on `self` with a new, structured, modifier. An
+    /// empty modifier will remove any existing modifier.
+    pub fn with_structured_modifier(&self, modifier: Modifier) -> Self {
+        LocaleString {
+            language_code: self.language_code.clone(),
+            territory: self.territory.clone(),
+            code_set: self.code_set.clone(),
+            modifier: if modifier.is_empty() {
+                None
+            } else {
+                Some(modifier)
+            },
+        }
+    }
+
+    /// Return the current modifier in its structured form.
+    pub fn structured_modifier(&self) -> Option<Modifier> {
+        self.modifier.clone()
+    }
+
+    /// Return the kind of the current territory, if any.
+    pub fn territory_kind(&self) -> Option<TerritoryKind> {
+        self.territory.as_ref().and_then(|t| TerritoryKind::of(t))
+    }
+
+    /// Return the current code set in the form produced by the glibc function
+    /// `_nl_normalize_codeset`; only ASCII letters and digits are kept, letters are
+    /// converted to lower-case and a name made only of digits is prefixed with `iso`.
+    /// For example both `UTF-8` and `utf8` become `utf8`, and `8859-1` becomes `iso88591`.
+    pub fn normalized_code_set(&self) -> Option<String> {
+        self.code_set
+            .as_ref()
+            .map(|code_set| normalize_code_set(code_set))
+    }
+
+    /// Return the current code set in its display form, the upper-case name of the
+    /// corresponding glibc character map such as `UTF-8` or `ISO-8859-1`; code sets
+    /// that do not correspond to a known character map are returned unchanged.
+    pub fn display_code_set(&self) -> Option<String> {
+        self.code_set
+            .as_ref()
+            .map(|code_set| display_code_set(code_set))
+    }
+
+    /// Return a new identifier based on `self` with the code set, if any, in the
+    /// glibc normalized form; this is the form listed by `locale -a`, as in `en_US.utf8`.
+    pub fn to_normalized(&self) -> Self {
+        LocaleString {
+            code_set: self.normalized_code_set(),
+            ..self.clone()
+        }
+    }
+
+    /// Return a new identifier based on `self` with the code set, if any, in its
+    /// display form, as in `en_US.UTF-8`.
+    pub fn to_display(&self) -> Self {
+        LocaleString {
+            code_set: self.display_code_set(),
+            ..self.clone()
+        }
+    }
+
+    /// Return the registered character set named by the current code set, if any.
+    #[cfg(feature = "charsets")]
+    pub fn charset(&self) -> Option<&'static crate::registry::charsets::Charset> {
+        self.code_set
+            .as_ref()
+            .and_then(|code_set| crate::registry::charsets::lookup(code_set))
+    }
+
+    /// Returns `true` if `self` and `other` have the same code set, comparing code set
+    /// names and aliases as described in the `registry::charsets` module; two
+    /// identifiers without a code set are also considered to have the same code set.
+    #[cfg(feature = "charsets")]
+    pub fn same_code_set(&self, other: &LocaleString) -> bool {
+        match (&self.code_set, &other.code_set) {
+            (Some(lhs), Some(rhs)) => crate::registry::charsets::are_equivalent(lhs, rhs),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Return the ISO 15924 script code denoted by the current modifier, if any. The
+    /// modifier must be a single identifier that is either a registered script code
+    /// in title case, such as `@Latn`, or a glibc script name such as `@latin` or `@cyrillic`.
+    pub fn script_code(&self) -> Option<String> {
+        let identifier = self.modifier.as_ref()?.identifier()?;
+        match SCRIPT_MODIFIERS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(identifier))
+        {
+            Some((_, code)) => Some(code.to_string()),
+            None if is_script_code(identifier) => Some(identifier.to_string()),
+            None => None,
+        }
+    }
+
+    /// Return the ISO 15924 script denoted by the current modifier, if any; this is
+    /// `script_code` checked against the script registry.
+    #[cfg(feature = "scripts")]
+    pub fn script(&self) -> Option<&'static crate::registry::scripts::Script> {
+        self.script_code()
+            .and_then(|code| crate::registry::scripts::lookup(&code))
+    }
+}
+
 impl LocaleIdentifier for LocaleString {
     fn new(language_code: String) -> LocaleResult<Self> {
-        if language_code.len() != 2 || !language_code.chars().all(|c| c.is_lowercase()) {
+        if !is_valid_language_code(&language_code) {
             return Err(LocaleError::InvalidLanguageCode);
         };
 
@@ -107,7 +323,7 @@ impl LocaleIdentifier for LocaleString {
     }
 
     fn with_language(&self, language_code: String) -> LocaleResult<Self> {
-        if language_code.len() != 2 || !language_code.chars().all(|c| c.is_lowercase()) {
+        if !is_valid_language_code(&language_code) {
             return Err(LocaleError::InvalidLanguageCode);
         };
 
@@ -120,10 +336,7 @@ impl LocaleIdentifier for LocaleString {
     }
 
     fn with_territory(&self, territory: String) -> LocaleResult<Self> {
-        if territory.len() < 2
-            || territory.len() > 2
-            || !territory.chars().all(|c| c.is_uppercase())
-        {
+        if !is_valid_territory(&territory) {
             return Err(LocaleError::InvalidTerritoryCode);
         };
 
@@ -136,7 +349,7 @@ impl LocaleIdentifier for LocaleString {
     }
 
     fn with_code_set(&self, code_set: String) -> LocaleResult<Self> {
-        if code_set.chars().all(|c| c.is_whitespace()) {
+        if !is_valid_code_set(&code_set) {
             return Err(LocaleError::InvalidCodeSet);
         };
         Ok(LocaleString {
@@ -148,30 +361,25 @@ impl LocaleIdentifier for LocaleString {
     }
 
     fn with_modifier(&self, modifier: String) -> LocaleResult<Self> {
-        Ok(LocaleString {
-            language_code: self.language_code.clone(),
-            territory: self.territory.clone(),
-            code_set: self.code_set.clone(),
-            modifier: Some(modifier),
-        })
+        match Modifier::from_str(&modifier) {
+            Ok(modifier) => Ok(self.with_structured_modifier(modifier)),
+            Err(_) => Err(LocaleError::InvalidModifier),
+        }
     }
 
-    fn with_modifiers<K, V>(&self, modifiers: HashMap<K, V>) -> LocaleResult<Self>
+    fn with_modifiers_ordered<I>(&self, modifiers: I, order: ModifierOrder) -> LocaleResult<Self>
     where
-        K: Display,
-        V: Display,
+        I: IntoIterator,
+        I::Item: ModifierPair,
     {
-        let modifier_strings: Vec<String> = modifiers
-            .iter()
-            .map(|(key, value)| format!("{}={}", key, value))
-            .collect();
-
-        Ok(LocaleString {
-            language_code: self.language_code.clone(),
-            territory: self.territory.clone(),
-            code_set: self.code_set.clone(),
-            modifier: Some(modifier_strings.join(";")),
-        })
+        let mut modifier = Modifier::new();
+        for pair in modifiers {
+            let _ = modifier.insert(pair.key(), pair.value())?;
+        }
+        if order == ModifierOrder::Sorted {
+            modifier.sort();
+        }
+        Ok(self.with_structured_modifier(modifier))
     }
 
     fn language_code(&self) -> String {
@@ -187,7 +395,7 @@ impl LocaleIdentifier for LocaleString {
     }
 
     fn modifier(&self) -> Option<String> {
-        self.modifier.clone()
+        self.modifier.as_ref().map(|modifier| modifier.to_string())
     }
 }
 
@@ -220,56 +428,293 @@ impl FromStr for LocaleString {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        lazy_static! {
-            static ref RE: Regex =
-                Regex::new(r"^([a-z][a-z]+)(_[A-Z][A-Z]+)?(\.[A-Z][a-zA-Z0-9\-_]+)?(@\w+)?$")
-                    .unwrap();
-        }
-
         if s.is_empty() {
-            return Err(ParseError::EmptyString);
+            return Err(ParseError::new(ParseErrorKind::EmptyString, s, 0..0));
         }
 
-        if s == "C" || s == "POSIX" {
-            return Err(ParseError::PosixUnsupported);
+        let (rest, modifier) = split_component(s, SEP_MODIFIER);
+        let (rest, code_set) = split_component(rest, SEP_CODE_SET);
+        let (language_code, territory) = split_component(rest, SEP_TERRITORY);
+
+        if territory.is_none() && (language_code == "C" || language_code == "POSIX") {
+            return Err(ParseError::new(
+                ParseErrorKind::PosixUnsupported,
+                s,
+                0..s.len(),
+            ));
         }
 
-        match RE.captures(s) {
-            None => Err(ParseError::RegexFailure),
-            Some(groups) => {
-                let mut locale =
-                    LocaleString::new(groups.get(1).unwrap().as_str().to_string()).unwrap();
-                if let Some(group_str) = groups.get(2) {
-                    locale = locale
-                        .with_territory(group_str.as_str()[1..].to_string())
-                        .unwrap();
-                }
-                if let Some(group_str) = groups.get(3) {
-                    locale = locale
-                        .with_code_set(group_str.as_str()[1..].to_string())
-                        .unwrap();
-                }
-                if let Some(group_str) = groups.get(4) {
-                    locale = locale
-                        .with_modifier(group_str.as_str()[1..].to_string())
-                        .unwrap();
+        // The string is only split into its components above, all validation is left
+        // to the builder methods so that anything the builder can construct can be
+        // parsed, and vice versa.
+        let error = |kind, offset: usize, component: &str| {
+            ParseError::new(kind, s, offset..offset + component.len())
+        };
+        let mut locale = LocaleString::new(language_code.to_string())
+            .map_err(|_| error(ParseErrorKind::InvalidLanguageCode, 0, language_code))?;
+        if let Some((offset, territory)) = territory {
+            locale = locale
+                .with_territory(territory.to_string())
+                .map_err(|_| error(ParseErrorKind::InvalidTerritoryCode, offset, territory))?;
+        }
+        if let Some((offset, code_set)) = code_set {
+            locale = locale
+                .with_code_set(code_set.to_string())
+                .map_err(|_| error(ParseErrorKind::InvalidCodeSet, offset, code_set))?;
+        }
+        if let Some((offset, modifier)) = modifier {
+            let modifier = match Modifier::from_str(modifier) {
+                Ok(modifier) => modifier,
+                Err(e) if e.kind() == ParseErrorKind::EmptyString => {
+                    return Err(error(ParseErrorKind::InvalidModifier, offset, modifier))
                 }
-                Ok(locale)
+                Err(e) => return Err(e.within(s, offset)),
+            };
+            locale = locale.with_structured_modifier(modifier);
+        }
+        Ok(locale)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - TerritoryKind
+// ------------------------------------------------------------------------------------------------
+
+/// The UN M.49 codes for macro-regions, as opposed to countries or areas.
+const MACRO_REGIONS: [&str; 32] = [
+    "001", "002", "003", "005", "009", "011", "013", "014", "015", "017", "018", "019", "021",
+    "029", "030", "034", "035", "039", "053", "054", "057", "061", "142", "143", "145", "150",
+    "151", "154", "155", "202", "419", "830",
+];
+
+impl TerritoryKind {
+    /// Return the kind of the given territory code, or `None` if the code is not
+    /// a syntactically valid territory.
+    pub fn of(territory: &str) -> Option<Self> {
+        if !is_valid_territory(territory) {
+            None
+        } else if MACRO_REGIONS.contains(&territory) {
+            Some(TerritoryKind::MacroRegion)
+        } else {
+            Some(TerritoryKind::Country)
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - ParseError
+// ------------------------------------------------------------------------------------------------
+
+impl ParseError {
+    /// Construct a new error of the given kind, detected at `span` within `input`.
+    pub fn new(kind: ParseErrorKind, input: &str, span: Range<usize>) -> Self {
+        ParseError {
+            kind,
+            input: input.to_string(),
+            span,
+        }
+    }
+
+    /// Return the kind of error.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Return the component that failed to parse, if the error is not with the
+    /// input as a whole.
+    pub fn component(&self) -> Option<Component> {
+        self.kind.component()
+    }
+
+    /// Return the complete string that was being parsed.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Return the span of bytes within `input()` at which the error was detected.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Return the byte offset within `input()` at which the error was detected.
+    pub fn offset(&self) -> usize {
+        self.span.start
+    }
+
+    /// Return the offending text within `input()`.
+    pub fn text(&self) -> &str {
+        self.input.get(self.span.clone()).unwrap_or_default()
+    }
+
+    /// Re-base an error from parsing a component in isolation to the complete
+    /// `input` where the component started at `offset`.
+    pub(crate) fn within(self, input: &str, offset: usize) -> Self {
+        ParseError {
+            kind: self.kind,
+            input: input.to_string(),
+            span: (self.span.start + offset)..(self.span.end + offset),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.kind, self.component()) {
+            (ParseErrorKind::EmptyString, _) => {
+                write!(f, "the empty string is not a valid locale identifier")
             }
+            (ParseErrorKind::PosixUnsupported, _) => write!(
+                f,
+                "{:?} is the POSIX minimal locale, not a locale identifier",
+                self.input
+            ),
+            (_, Some(component)) => write!(
+                f,
+                "invalid {} {:?} at {}..{} in {:?}",
+                component,
+                self.text(),
+                self.span.start,
+                self.span.end,
+                self.input
+            ),
+            (_, None) => write!(f, "invalid locale identifier {:?}", self.input),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - ParseErrorKind
+// ------------------------------------------------------------------------------------------------
+
+impl ParseErrorKind {
+    /// Return the component this kind of error relates to, if it is not with
+    /// the input as a whole.
+    pub fn component(&self) -> Option<Component> {
+        match self {
+            ParseErrorKind::EmptyString | ParseErrorKind::PosixUnsupported => None,
+            ParseErrorKind::InvalidLanguageCode => Some(Component::Language),
+            ParseErrorKind::InvalidTerritoryCode => Some(Component::Territory),
+            ParseErrorKind::InvalidCodeSet => Some(Component::CodeSet),
+            ParseErrorKind::InvalidModifier => Some(Component::Modifier),
+            ParseErrorKind::InvalidVariant => Some(Component::Variant),
+            ParseErrorKind::InvalidExtension => Some(Component::Extension),
+            ParseErrorKind::InvalidPrivateUse => Some(Component::PrivateUse),
+            ParseErrorKind::InvalidPath => Some(Component::Path),
+            ParseErrorKind::InvalidLanguageRange => Some(Component::LanguageRange),
         }
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+// Implementations - Component
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Component::Language => "language code",
+                Component::Territory => "territory code",
+                Component::CodeSet => "code set",
+                Component::Modifier => "modifier",
+                Component::Variant => "variant",
+                Component::Extension => "extension",
+                Component::PrivateUse => "private use",
+                Component::Path => "path",
+                Component::LanguageRange => "language range",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Split `s` at the first occurrence of `separator`, returning the part before the
+/// separator and, if the separator was found, the part after it along with its
+/// byte offset in `s`.
+fn split_component(s: &str, separator: char) -> (&str, Option<(usize, &str)>) {
+    match s.find(separator) {
+        Some(index) => (&s[..index], Some((index + 1, &s[index + 1..]))),
+        None => (s, None),
+    }
+}
+
+/// Language codes are either the 2-character ISO 639-1 codes or the 3-character
+/// ISO 639-2/639-3 codes, in both cases lower-case ASCII letters only.
+fn is_valid_language_code(language_code: &str) -> bool {
+    (language_code.len() == 2 || language_code.len() == 3)
+        && language_code.chars().all(|c| c.is_ascii_lowercase())
+}
+
+/// Territory codes are the 2-character ISO 3166-1 codes, upper-case ASCII letters only,
+/// or the 3-digit UN M.49 codes.
+fn is_valid_territory(territory: &str) -> bool {
+    (territory.len() == 2 && territory.chars().all(|c| c.is_ascii_uppercase()))
+        || (territory.len() == 3 && territory.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Script codes are registered ISO 15924 codes in title case, as in `Latn`.
+pub(crate) fn is_script_code(code: &str) -> bool {
+    crate::registry::is_script_code(code)
+}
+
+/// The glibc `_nl_normalize_codeset` algorithm.
+fn normalize_code_set(code_set: &str) -> String {
+    let normalized: String = code_set
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if normalized.chars().all(|c| c.is_ascii_digit()) {
+        format!("iso{}", normalized)
+    } else {
+        normalized
+    }
+}
+
+/// The display form of `code_set`, or `code_set` itself if no display form is known.
+pub(crate) fn display_code_set(code_set: &str) -> String {
+    let normalized = normalize_code_set(code_set);
+    DISPLAY_CODE_SETS
+        .iter()
+        .find(|display| normalize_code_set(display) == normalized)
+        .map(|display| display.to_string())
+        .unwrap_or_else(|| code_set.to_string())
+}
+
+/// Code set names start with an ASCII letter or digit, followed by any ASCII letters,
+/// digits, or the characters `-`, `_`, `.`, and `:`.
+pub(crate) fn is_valid_code_set(code_set: &str) -> bool {
+    code_set
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphanumeric())
+        && code_set
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' || c == ':')
+}
+
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
+    use std::error::Error;
     use std::str::FromStr;
 
-    use crate::{LocaleError, LocaleIdentifier, LocaleString};
+    use proptest::prelude::*;
+
+    use crate::id::ModifierOrder;
+    use crate::string::{Component, ParseError, ParseErrorKind, TerritoryKind};
+    use crate::{LocaleError, LocaleIdentifier, LocaleString, Modifier};
 
     // --------------------------------------------------------------------------------------------
     #[test]
@@ -288,6 +733,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bad_constructor_three_letter_case() {
+        assert_eq!(
+            LocaleString::new("Ast".to_string()),
+            Err(LocaleError::InvalidLanguageCode)
+        );
+    }
+
     #[test]
     fn test_bad_territory_length() {
         assert_eq!(
@@ -298,6 +751,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bad_numeric_territory() {
+        let locale = LocaleString::new("es".to_string()).unwrap();
+        assert_eq!(
+            locale.with_territory("41".to_string()),
+            Err(LocaleError::InvalidTerritoryCode)
+        );
+        assert_eq!(
+            locale.with_territory("4190".to_string()),
+            Err(LocaleError::InvalidTerritoryCode)
+        );
+    }
+
     #[test]
     fn test_bad_country_case() {
         assert_eq!(
@@ -317,6 +783,19 @@ mod tests {
         assert_eq!(locale.modifier(), None);
     }
 
+    #[test]
+    fn test_three_letter_constructor() {
+        let locale = LocaleString::new("ast".to_string()).unwrap();
+        assert_eq!(locale.language_code(), "ast".to_string());
+        assert_eq!(
+            locale
+                .with_language("fil".to_string())
+                .unwrap()
+                .language_code(),
+            "fil".to_string()
+        );
+    }
+
     #[test]
     fn test_with_language() {
         let locale = LocaleString::new("en".to_string()).unwrap();
@@ -338,6 +817,119 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_with_numeric_territory() {
+        let locale = LocaleString::new("es".to_string())
+            .unwrap()
+            .with_territory("419".to_string())
+            .unwrap();
+        assert_eq!(locale.territory(), Some("419".to_string()));
+        assert_eq!(locale.territory_kind(), Some(TerritoryKind::MacroRegion));
+        assert_eq!(locale.to_string(), "es_419");
+    }
+
+    #[test]
+    fn test_territory_kind() {
+        assert_eq!(TerritoryKind::of("US"), Some(TerritoryKind::Country));
+        assert_eq!(TerritoryKind::of("840"), Some(TerritoryKind::Country));
+        assert_eq!(TerritoryKind::of("001"), Some(TerritoryKind::MacroRegion));
+        assert_eq!(TerritoryKind::of("150"), Some(TerritoryKind::MacroRegion));
+        assert_eq!(TerritoryKind::of("us"), None);
+        assert_eq!(
+            LocaleString::new("en".to_string())
+                .unwrap()
+                .territory_kind(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_script_code() {
+        let script = |s: &str| LocaleString::from_str(s).unwrap().script_code();
+        assert_eq!(script("sr_RS@latin"), Some("Latn".to_string()));
+        assert_eq!(script("uz_UZ.UTF-8@cyrillic"), Some("Cyrl".to_string()));
+        assert_eq!(script("ks_IN@devanagari"), Some("Deva".to_string()));
+        assert_eq!(script("zh_TW@Hant"), Some("Hant".to_string()));
+        assert_eq!(script("de_DE@euro"), None);
+        assert_eq!(script("en_US@Test"), None);
+        assert_eq!(script("ca_ES@valencia"), None);
+        assert_eq!(script("en_US@script=Latn"), None);
+        assert_eq!(script("en_US"), None);
+    }
+
+    #[cfg(feature = "scripts")]
+    #[test]
+    fn test_script() {
+        let locale = LocaleString::from_str("sr_RS@latin").unwrap();
+        assert_eq!(locale.script().unwrap().name(), "Latin");
+        let locale = LocaleString::from_str("en_US@Xxxx").unwrap();
+        assert_eq!(locale.script_code(), None);
+        assert!(locale.script().is_none());
+    }
+
+    #[cfg(feature = "charsets")]
+    #[test]
+    fn test_with_code_set_strict() {
+        let locale = LocaleString::new("en".to_string()).unwrap();
+        assert_eq!(
+            locale
+                .with_code_set_strict("utf8".to_string())
+                .unwrap()
+                .to_string(),
+            "en.UTF-8"
+        );
+        assert_eq!(
+            locale.with_code_set_strict("NOT-A-CHARSET".to_string()),
+            Err(LocaleError::InvalidCodeSet)
+        );
+        let other = LocaleString::from_str("en.ISO8859-1").unwrap();
+        assert!(!locale.same_code_set(&other));
+        assert!(other.same_code_set(&LocaleString::from_str("en.latin1").unwrap()));
+    }
+
+    #[test]
+    fn test_normalized_code_set() {
+        let normalized = |s: &str| {
+            LocaleString::from_str(s)
+                .unwrap()
+                .normalized_code_set()
+                .unwrap()
+        };
+        assert_eq!(normalized("en_US.UTF-8"), "utf8");
+        assert_eq!(normalized("en_US.utf8"), "utf8");
+        assert_eq!(normalized("de_DE.ISO-8859-15"), "iso885915");
+        assert_eq!(normalized("ja_JP.eucJP"), "eucjp");
+        assert_eq!(normalized("en_US.8859-1"), "iso88591");
+        assert_eq!(normalized("ka_GE.GEORGIAN-PS"), "georgianps");
+        let locale = LocaleString::from_str("en_US.UTF-8@euro").unwrap();
+        assert_eq!(locale.to_normalized().to_string(), "en_US.utf8@euro");
+        assert_eq!(
+            LocaleString::from_str("en_US")
+                .unwrap()
+                .to_normalized()
+                .to_string(),
+            "en_US"
+        );
+    }
+
+    #[test]
+    fn test_display_code_set() {
+        let display = |s: &str| {
+            LocaleString::from_str(s)
+                .unwrap()
+                .display_code_set()
+                .unwrap()
+        };
+        assert_eq!(display("en_US.utf8"), "UTF-8");
+        assert_eq!(display("de_DE.iso885915"), "ISO-8859-15");
+        assert_eq!(display("ja_JP.eucjp"), "EUC-JP");
+        assert_eq!(display("hy_AM.armscii8"), "ARMSCII-8");
+        assert_eq!(display("en_US.x-custom"), "x-custom");
+        let locale = LocaleString::from_str("en_US.utf8").unwrap();
+        assert_eq!(locale.to_display().to_string(), "en_US.UTF-8");
+        assert_eq!(locale.to_display().to_normalized(), locale);
+    }
+
     #[test]
     fn test_with_code_set() {
         let locale = LocaleString::new("en".to_string()).unwrap();
@@ -365,19 +957,86 @@ mod tests {
     #[test]
     fn test_with_modifiers() {
         let locale = LocaleString::new("en".to_string()).unwrap();
-        let modifiers: HashMap<&str, &str> = [("collation", "pinyin"), ("currency", "CNY")]
+        let modifiers: HashMap<&str, &str> = [("currency", "CNY"), ("collation", "pinyin")]
             .iter()
             .cloned()
             .collect();
-        assert!(locale
-            .with_modifiers(modifiers)
-            .unwrap()
-            .modifier()
+        assert_eq!(
+            locale.with_modifiers(&modifiers).unwrap().modifier(),
+            Some("collation=pinyin;currency=CNY".to_string())
+        );
+        assert_eq!(
+            locale.with_modifiers(modifiers).unwrap().modifier(),
+            Some("collation=pinyin;currency=CNY".to_string())
+        );
+    }
+
+    #[test]
+    fn test_with_modifiers_collections() {
+        let locale = LocaleString::new("en".to_string()).unwrap();
+        let pairs = [("currency", "CNY"), ("collation", "pinyin")];
+        assert_eq!(
+            locale.with_modifiers(&pairs).unwrap().modifier(),
+            Some("collation=pinyin;currency=CNY".to_string())
+        );
+        assert_eq!(
+            locale
+                .with_modifiers(pairs.iter().cloned().collect::<BTreeMap<&str, &str>>())
+                .unwrap()
+                .modifier(),
+            Some("collation=pinyin;currency=CNY".to_string())
+        );
+        assert_eq!(
+            locale
+                .with_modifiers(vec![("version", 2)].into_iter())
+                .unwrap()
+                .modifier(),
+            Some("version=2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_with_modifiers_ordered() {
+        let locale = LocaleString::new("en".to_string()).unwrap();
+        let pairs = vec![("currency", "CNY"), ("collation", "pinyin")];
+        assert_eq!(
+            locale
+                .with_modifiers_ordered(&pairs, ModifierOrder::Insertion)
+                .unwrap()
+                .modifier(),
+            Some("currency=CNY;collation=pinyin".to_string())
+        );
+        assert_eq!(
+            locale
+                .with_modifiers_ordered(&pairs, ModifierOrder::Sorted)
+                .unwrap()
+                .modifier(),
+            Some("collation=pinyin;currency=CNY".to_string())
+        );
+    }
+
+    #[test]
+    fn test_with_bad_modifier() {
+        let locale = LocaleString::new("en".to_string()).unwrap();
+        assert_eq!(
+            locale.with_modifier("collation=pinyin;;".to_string()),
+            Err(LocaleError::InvalidModifier)
+        );
+    }
+
+    #[test]
+    fn test_structured_modifier() {
+        let locale = LocaleString::new("en".to_string())
             .unwrap()
-            .contains("collation=pinyin"));
-        //        assert!(
-        //            locale.with_modifiers(modifiers).get_modifier().unwrap().contains("currency=CNY")
-        //        );
+            .with_modifier("collation=pinyin;currency=CNY".to_string())
+            .unwrap();
+        let mut modifier = locale.structured_modifier().unwrap();
+        assert_eq!(modifier.get("currency"), Some("CNY"));
+        let _ = modifier.remove("collation");
+        let locale = locale.with_structured_modifier(modifier);
+        assert_eq!(locale.to_string(), "en@currency=CNY");
+        let locale = locale.with_structured_modifier(Modifier::new());
+        assert_eq!(locale.modifier(), None);
     }
 
     // --------------------------------------------------------------------------------------------
@@ -441,4 +1100,162 @@ mod tests {
             _ => panic!("LocaleString::from_str failure"),
         }
     }
+
+    #[test]
+    fn test_from_str_three_letter() {
+        for (s, language, territory) in &[
+            ("ast_ES.UTF-8", "ast", "ES"),
+            ("fil_PH", "fil", "PH"),
+            ("yue_HK", "yue", "HK"),
+            ("nds_DE", "nds", "DE"),
+            ("es_419.UTF-8", "es", "419"),
+        ] {
+            match LocaleString::from_str(s) {
+                Ok(locale) => {
+                    assert_eq!(&locale.language_code(), language);
+                    assert_eq!(locale.territory(), Some(territory.to_string()));
+                    assert_eq!(&locale.to_string(), s);
+                }
+                _ => panic!("LocaleString::from_str failure"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_str_bad_language() {
+        assert!(LocaleString::from_str("english_US").is_err());
+        assert!(LocaleString::from_str("e_US").is_err());
+    }
+
+    #[test]
+    fn test_from_str_invalid_components() {
+        let error = |s, kind, span| Err(ParseError::new(kind, s, span));
+        assert_eq!(
+            LocaleString::from_str(""),
+            error("", ParseErrorKind::EmptyString, 0..0)
+        );
+        assert_eq!(
+            LocaleString::from_str("C"),
+            error("C", ParseErrorKind::PosixUnsupported, 0..1)
+        );
+        assert_eq!(
+            LocaleString::from_str("C.UTF-8"),
+            error("C.UTF-8", ParseErrorKind::PosixUnsupported, 0..7)
+        );
+        assert_eq!(
+            LocaleString::from_str("EN_US"),
+            error("EN_US", ParseErrorKind::InvalidLanguageCode, 0..2)
+        );
+        assert_eq!(
+            LocaleString::from_str("en_USA"),
+            error("en_USA", ParseErrorKind::InvalidTerritoryCode, 3..6)
+        );
+        assert_eq!(
+            LocaleString::from_str("en_US. UTF-8"),
+            error("en_US. UTF-8", ParseErrorKind::InvalidCodeSet, 6..12)
+        );
+        assert_eq!(
+            LocaleString::from_str("en_US@"),
+            error("en_US@", ParseErrorKind::InvalidModifier, 6..6)
+        );
+        assert_eq!(
+            LocaleString::from_str("en_US@a;;b"),
+            error("en_US@a;;b", ParseErrorKind::InvalidModifier, 8..8)
+        );
+        assert_eq!(
+            LocaleString::from_str("en_US.UTF-8@a=1;b=2;c=é"),
+            error(
+                "en_US.UTF-8@a=1;b=2;c=é",
+                ParseErrorKind::InvalidModifier,
+                20..24
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let error = LocaleString::from_str("en_USA.UTF-8").unwrap_err();
+        assert_eq!(error.component(), Some(Component::Territory));
+        assert_eq!(error.text(), "USA");
+        assert_eq!(
+            error.to_string(),
+            "invalid territory code \"USA\" at 3..6 in \"en_USA.UTF-8\""
+        );
+        let error = LocaleError::from(error);
+        assert!(error.source().is_some());
+        assert_eq!(
+            error.to_string(),
+            "the locale string could not be parsed: invalid territory code \"USA\" at 3..6 in \"en_USA.UTF-8\""
+        );
+        let error: Box<dyn Error> = Box::new(LocaleString::from_str("").unwrap_err());
+        assert_eq!(
+            error.to_string(),
+            "the empty string is not a valid locale identifier"
+        );
+    }
+
+    #[test]
+    fn test_from_str_modifier_pairs() {
+        let s = "en_US.UTF-8@collation=pinyin;currency=CNY";
+        let locale = LocaleString::from_str(s).unwrap();
+        assert_eq!(
+            locale.modifier(),
+            Some("collation=pinyin;currency=CNY".to_string())
+        );
+        assert_eq!(locale.to_string(), s);
+    }
+
+    // --------------------------------------------------------------------------------------------
+    fn arb_locale_string() -> impl Strategy<Value = LocaleString> {
+        (
+            "[a-z]{2,3}",
+            proptest::option::of("[A-Z]{2}|[0-9]{3}"),
+            proptest::option::of("[a-zA-Z0-9][a-zA-Z0-9_.:-]{0,15}"),
+            proptest::collection::vec(
+                (
+                    "[a-zA-Z0-9_-]{1,8}",
+                    proptest::option::of("[a-zA-Z0-9_.+-]{1,8}"),
+                ),
+                0..4,
+            ),
+        )
+            .prop_map(|(language, territory, code_set, entries)| {
+                let mut locale = LocaleString::new(language).unwrap();
+                if let Some(territory) = territory {
+                    locale = locale.with_territory(territory).unwrap();
+                }
+                if let Some(code_set) = code_set {
+                    locale = locale.with_code_set(code_set).unwrap();
+                }
+                let mut modifier = Modifier::new();
+                for (key, value) in entries {
+                    match value {
+                        Some(value) => {
+                            let _ = modifier.insert(key, value).unwrap();
+                        }
+                        None => modifier.insert_identifier(key).unwrap(),
+                    }
+                }
+                locale.with_structured_modifier(modifier)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_display_from_str_round_trip(locale in arb_locale_string()) {
+            let s = locale.to_string();
+            prop_assert_eq!(LocaleString::from_str(&s), Ok(locale));
+        }
+
+        #[test]
+        fn prop_from_str_display_round_trip(s in "[a-z]{2,3}(_([A-Z]{2}|[0-9]{3}))?(\\.[a-zA-Z0-9][a-zA-Z0-9_.:-]{0,15})?(@[a-z]{1,8}(=[a-z0-9]{1,8})?)?") {
+            let locale = LocaleString::from_str(&s).unwrap();
+            prop_assert_eq!(locale.to_string(), s);
+        }
+
+        #[test]
+        fn prop_from_str_never_panics(s in "\\PC*") {
+            let _ = LocaleString::from_str(&s);
+        }
+    }
 }
@@ -0,0 +1,808 @@
+/*!
+The `LanguageTag` type provides a structure for IETF BCP 47 language tags.
+
+## Standards
+
+> A language tag is composed from a sequence of one or more "subtags", each of which
+> refines or narrows the range of language identified by the overall tag. Subtags, in
+> turn, are a sequence of alphanumeric characters (letters and digits), distinguished and
+> separated from other subtags in a tag by a hyphen ("-", \[Unicode\] U+002D).
+
+Language tags are defined in this format:
+`language[-extlang][-script][-region]*[-variant]*[-extension][-privateuse]`.
+For example, Traditional Chinese as used in Taiwan is `zh-Hant-TW`.
+
+* `language` = [ISO 639](https://en.wikipedia.org/wiki/ISO_639) 2 or 3-character language
+  codes (or registered 5 to 8-character codes).
+* `extlang` = up to three 3-character extended language subtags, `zh-yue`.
+* `script` = [ISO 15924](http://unicode.org/iso15924/iso15924-codes.html) 4-character
+  script codes, `Latn`.
+* `region` = [ISO 3166-1](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) 2-character
+  country codes, or [UN M.49](https://unstats.un.org/unsd/methodology/m49/) 3-digit region
+  codes, `US` or `419`.
+* `variant` = registered 5 to 8-character variants, or 4-character variants beginning with a
+  digit, `valencia` or `1996`.
+* `extension` = a single character (other than `x`) followed by one or more 2 to 8-character
+  subtags, `u-nu-latn`.
+* `privateuse` = the character `x` followed by one or more 1 to 8-character subtags,
+  `x-whatever`.
+
+Tags are case-insensitive, when parsed they are stored in the recommended case; language,
+variant, extension and private use subtags in lower-case, script subtags in title-case,
+and region subtags in upper-case. The irregular and regular _grandfathered_ tags from RFC
+5646 are recognized as whole tags, and private use tags (those starting with `x-`) are
+also supported.
+
+See also:
+
+* [IETF _Tags for Identifying Languages_](https://tools.ietf.org/html/bcp47)
+* [IETF _RFC 5646_](https://tools.ietf.org/html/rfc5646)
+* [W3C _Language tags in HTML and XML_](https://www.w3.org/International/articles/language-tags/)
+
+## Examples
+
+```
+use locale_types::{LanguageTag, LocaleIdentifier};
+use std::str::FromStr;
+
+let tag = LanguageTag::from_str("sr-latn-rs-u-nu-latn").unwrap();
+assert_eq!(tag.language_code(), "sr".to_string());
+assert_eq!(tag.script(), Some("Latn".to_string()));
+assert_eq!(tag.territory(), Some("RS".to_string()));
+assert_eq!(tag.to_string(), "sr-Latn-RS-u-nu-latn".to_string());
+```
+
+*/
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::id::{LocaleIdentifier, ModifierOrder, ModifierPair};
+use crate::string::{is_script_code, ParseError, ParseErrorKind};
+use crate::{LocaleError, LocaleResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A `LanguageTag` is a representation of the IETF BCP 47 language tag, used
+/// in protocols such as HTTP and data formats such as HTML and XML. It implements
+/// the `LocaleIdentifier` trait.
+///
+/// As BCP 47 has no notion of a code set, the `code_set` methods of `LocaleIdentifier`
+/// are not supported. The POSIX modifier is mapped onto the `script` subtag where the
+/// modifier is a 4-character script code, or onto `variant` subtags otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageTag {
+    language: String,
+    extended_languages: Vec<String>,
+    script: Option<String>,
+    region: Option<String>,
+    variants: Vec<String>,
+    extensions: Vec<Extension>,
+    private_use: Vec<String>,
+    grandfathered: Option<String>,
+}
+
+/// An extension, identified by a single character `singleton`, within a
+/// `LanguageTag`; for example `u-nu-latn`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extension {
+    singleton: char,
+    subtags: Vec<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - LanguageTag
+// ------------------------------------------------------------------------------------------------
+
+const SEP_SUBTAG: char = '-';
+const PRIVATE_USE: &str = "x";
+
+const GRANDFATHERED: [&str; 26] = [
+    // irregular
+    "en-GB-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "sgn-BE-FR",
+    "sgn-BE-NL",
+    "sgn-CH-DE",
+    // regular
+    "art-lojban",
+    "cel-gaulish",
+    "no-bok",
+    "no-nyn",
+    "zh-guoyu",
+    "zh-hakka",
+    "zh-min",
+    "zh-min-nan",
+    "zh-xiang",
+];
+
+impl LanguageTag {
+    /// Return the extended language subtags, if any.
+    pub fn extended_languages(&self) -> Vec<String> {
+        self.extended_languages.clone()
+    }
+
+    /// Return the script subtag, if any.
+    pub fn script(&self) -> Option<String> {
+        self.script.clone()
+    }
+
+    /// Return the variant subtags, if any.
+    pub fn variants(&self) -> Vec<String> {
+        self.variants.clone()
+    }
+
+    /// Return the extensions, if any.
+    pub fn extensions(&self) -> Vec<Extension> {
+        self.extensions.clone()
+    }
+
+    /// Return the private use subtags, if any; these do not include the
+    /// leading `x` singleton.
+    pub fn private_use(&self) -> Vec<String> {
+        self.private_use.clone()
+    }
+
+    /// Returns `true` if this tag is one of the grandfathered tags from RFC 5646.
+    pub fn is_grandfathered(&self) -> bool {
+        self.grandfathered.is_some()
+    }
+
+    /// Returns `true` if this tag consists only of private use subtags, `x-whatever`.
+    pub fn is_private_use(&self) -> bool {
+        self.grandfathered.is_none() && self.language.is_empty()
+    }
+
+    /// Return a new tag based on `self` with a new set of extended language subtags.
+    pub fn with_extended_languages(&self, extended_languages: Vec<String>) -> LocaleResult<Self> {
+        if !self.is_langtag()
+            || self.language.len() > 3
+            || extended_languages.len() > 3
+            || !extended_languages.iter().all(|s| is_extended_language(s))
+        {
+            return Err(LocaleError::InvalidLanguageCode);
+        }
+        Ok(LanguageTag {
+            extended_languages: extended_languages
+                .iter()
+                .map(|s| s.to_ascii_lowercase())
+                .collect(),
+            ..self.clone()
+        })
+    }
+
+    /// Return a new tag based on `self` with a new script subtag.
+    pub fn with_script(&self, script: String) -> LocaleResult<Self> {
+        if !self.is_langtag() || !is_script(&script) {
+            return Err(LocaleError::InvalidModifier);
+        }
+        Ok(LanguageTag {
+            script: Some(title_case(&script)),
+            ..self.clone()
+        })
+    }
+
+    /// Return a new tag based on `self` with a new set of variant subtags.
+    pub fn with_variants(&self, variants: Vec<String>) -> LocaleResult<Self> {
+        let variants: Vec<String> = variants.iter().map(|s| s.to_ascii_lowercase()).collect();
+        if !self.is_langtag()
+            || !variants.iter().all(|s| is_variant(s))
+            || has_duplicates(&variants)
+        {
+            return Err(LocaleError::InvalidModifier);
+        }
+        Ok(LanguageTag {
+            variants,
+            ..self.clone()
+        })
+    }
+
+    /// Return a new tag based on `self` with an additional extension, replacing
+    /// any existing extension with the same singleton.
+    pub fn with_extension(&self, extension: Extension) -> LocaleResult<Self> {
+        if !self.is_langtag() {
+            return Err(LocaleError::InvalidModifier);
+        }
+        let mut extensions: Vec<Extension> = self
+            .extensions
+            .iter()
+            .filter(|e| e.singleton != extension.singleton)
+            .cloned()
+            .collect();
+        extensions.push(extension);
+        Ok(LanguageTag {
+            extensions,
+            ..self.clone()
+        })
+    }
+
+    /// Return a new tag based on `self` with a new set of private use subtags.
+    pub fn with_private_use(&self, private_use: Vec<String>) -> LocaleResult<Self> {
+        if self.is_grandfathered()
+            || private_use.is_empty()
+            || !private_use.iter().all(|s| is_private_use(s))
+        {
+            return Err(LocaleError::InvalidModifier);
+        }
+        Ok(LanguageTag {
+            private_use: private_use.iter().map(|s| s.to_ascii_lowercase()).collect(),
+            ..self.clone()
+        })
+    }
+
+    fn is_langtag(&self) -> bool {
+        self.grandfathered.is_none() && !self.language.is_empty()
+    }
+}
+
+impl LocaleIdentifier for LanguageTag {
+    fn new(language_code: String) -> LocaleResult<Self> {
+        if !is_language(&language_code) {
+            return Err(LocaleError::InvalidLanguageCode);
+        }
+        Ok(LanguageTag {
+            language: language_code.to_ascii_lowercase(),
+            extended_languages: Vec::new(),
+            script: None,
+            region: None,
+            variants: Vec::new(),
+            extensions: Vec::new(),
+            private_use: Vec::new(),
+            grandfathered: None,
+        })
+    }
+
+    fn with_language(&self, language_code: String) -> LocaleResult<Self> {
+        if !is_language(&language_code) {
+            return Err(LocaleError::InvalidLanguageCode);
+        }
+        if self.is_langtag() {
+            Ok(LanguageTag {
+                language: language_code.to_ascii_lowercase(),
+                extended_languages: if language_code.len() > 3 {
+                    Vec::new()
+                } else {
+                    self.extended_languages.clone()
+                },
+                ..self.clone()
+            })
+        } else {
+            Ok(LanguageTag {
+                private_use: self.private_use.clone(),
+                ..LanguageTag::new(language_code)?
+            })
+        }
+    }
+
+    fn with_territory(&self, territory: String) -> LocaleResult<Self> {
+        if !self.is_langtag() || !is_region(&territory) {
+            return Err(LocaleError::InvalidTerritoryCode);
+        }
+        Ok(LanguageTag {
+            region: Some(territory.to_ascii_uppercase()),
+            ..self.clone()
+        })
+    }
+
+    fn with_code_set(&self, _code_set: String) -> LocaleResult<Self> {
+        Err(LocaleError::Unsupported)
+    }
+
+    fn with_modifier(&self, modifier: String) -> LocaleResult<Self> {
+        // only a registered script code in title case, as in `@Latn`, is a script.
+        if is_script_code(&modifier) {
+            self.with_script(modifier)
+        } else {
+            self.with_variants(modifier.split(SEP_SUBTAG).map(str::to_string).collect())
+        }
+    }
+
+    fn with_modifiers_ordered<I>(&self, _modifiers: I, _order: ModifierOrder) -> LocaleResult<Self>
+    where
+        I: IntoIterator,
+        I::Item: ModifierPair,
+    {
+        Err(LocaleError::Unsupported)
+    }
+
+    fn language_code(&self) -> String {
+        if self.is_langtag() {
+            self.language.clone()
+        } else {
+            self.to_string()
+        }
+    }
+
+    fn territory(&self) -> Option<String> {
+        self.region.clone()
+    }
+
+    fn code_set(&self) -> Option<String> {
+        None
+    }
+
+    fn modifier(&self) -> Option<String> {
+        match &self.script {
+            Some(script) => Some(script.clone()),
+            None if !self.variants.is_empty() => Some(self.variants.join("-")),
+            None => None,
+        }
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(grandfathered) = &self.grandfathered {
+            return write!(f, "{}", grandfathered);
+        }
+        let mut subtags: Vec<String> = Vec::new();
+        if !self.language.is_empty() {
+            subtags.push(self.language.clone());
+        }
+        subtags.extend(self.extended_languages.iter().cloned());
+        if let Some(script) = &self.script {
+            subtags.push(script.clone());
+        }
+        if let Some(region) = &self.region {
+            subtags.push(region.clone());
+        }
+        subtags.extend(self.variants.iter().cloned());
+        subtags.extend(self.extensions.iter().map(|e| e.to_string()));
+        if !self.private_use.is_empty() {
+            subtags.push(PRIVATE_USE.to_string());
+            subtags.extend(self.private_use.iter().cloned());
+        }
+        write!(f, "{}", subtags.join("-"))
+    }
+}
+
+impl FromStr for LanguageTag {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::EmptyString, s, 0..0));
+        }
+
+        if let Some(grandfathered) = GRANDFATHERED.iter().find(|g| g.eq_ignore_ascii_case(s)) {
+            return Ok(LanguageTag {
+                language: String::new(),
+                extended_languages: Vec::new(),
+                script: None,
+                region: None,
+                variants: Vec::new(),
+                extensions: Vec::new(),
+                private_use: Vec::new(),
+                grandfathered: Some(grandfathered.to_string()),
+            });
+        }
+
+        let error = |kind, offset: usize, subtag: &str| {
+            ParseError::new(kind, s, offset..offset + subtag.len())
+        };
+        let lower_case = s.to_ascii_lowercase();
+        let mut offset = 0;
+        let mut subtags = lower_case
+            .split(SEP_SUBTAG)
+            .map(|subtag| {
+                let start = offset;
+                offset += subtag.len() + 1;
+                (start, subtag)
+            })
+            .peekable();
+        let mut tag = LanguageTag {
+            language: String::new(),
+            extended_languages: Vec::new(),
+            script: None,
+            region: None,
+            variants: Vec::new(),
+            extensions: Vec::new(),
+            private_use: Vec::new(),
+            grandfathered: None,
+        };
+
+        let (_, first) = subtags.next().unwrap();
+        let mut private_use_offset = 0;
+        if first != PRIVATE_USE {
+            if !is_language(first) {
+                return Err(error(ParseErrorKind::InvalidLanguageCode, 0, first));
+            }
+            tag.language = first.to_string();
+
+            if tag.language.len() <= 3 {
+                while tag.extended_languages.len() < 3
+                    && subtags.peek().is_some_and(|(_, s)| is_extended_language(s))
+                {
+                    tag.extended_languages
+                        .push(subtags.next().unwrap().1.to_string());
+                }
+            }
+            if subtags.peek().is_some_and(|(_, s)| is_script(s)) {
+                tag.script = Some(title_case(subtags.next().unwrap().1));
+            }
+            if subtags.peek().is_some_and(|(_, s)| is_region(s)) {
+                tag.region = Some(subtags.next().unwrap().1.to_ascii_uppercase());
+            }
+            while subtags.peek().is_some_and(|(_, s)| is_variant(s)) {
+                let (offset, variant) = subtags.next().unwrap();
+                if tag.variants.iter().any(|v| v == variant) {
+                    return Err(error(ParseErrorKind::InvalidVariant, offset, variant));
+                }
+                tag.variants.push(variant.to_string());
+            }
+            while let Some(singleton) = subtags.peek().and_then(|(_, s)| singleton(s)) {
+                let (offset, subtag) = subtags.next().unwrap();
+                if tag.extensions.iter().any(|e| e.singleton == singleton) {
+                    return Err(error(ParseErrorKind::InvalidExtension, offset, subtag));
+                }
+                let mut extension_subtags: Vec<String> = Vec::new();
+                while subtags.peek().is_some_and(|(_, s)| is_extension(s)) {
+                    extension_subtags.push(subtags.next().unwrap().1.to_string());
+                }
+                if extension_subtags.is_empty() {
+                    return Err(error(ParseErrorKind::InvalidExtension, offset, subtag));
+                }
+                tag.extensions.push(Extension {
+                    singleton,
+                    subtags: extension_subtags,
+                });
+            }
+            match subtags.next() {
+                None => return Ok(tag),
+                Some((offset, PRIVATE_USE)) => private_use_offset = offset,
+                Some((offset, subtag)) => {
+                    return Err(error(unexpected_subtag(subtag), offset, subtag))
+                }
+            }
+        }
+
+        for (offset, subtag) in subtags {
+            if !is_private_use(subtag) {
+                return Err(error(ParseErrorKind::InvalidPrivateUse, offset, subtag));
+            }
+            tag.private_use.push(subtag.to_string());
+        }
+        if tag.private_use.is_empty() {
+            return Err(error(
+                ParseErrorKind::InvalidPrivateUse,
+                private_use_offset,
+                PRIVATE_USE,
+            ));
+        }
+        Ok(tag)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations - Extension
+// ------------------------------------------------------------------------------------------------
+
+impl Extension {
+    /// Construct a new extension with the given singleton and subtags.
+    pub fn new(singleton: char, subtags: Vec<String>) -> LocaleResult<Self> {
+        let singleton = singleton.to_ascii_lowercase();
+        if singleton.to_string() == PRIVATE_USE
+            || !singleton.is_ascii_alphanumeric()
+            || subtags.is_empty()
+            || !subtags.iter().all(|s| is_extension(s))
+        {
+            return Err(LocaleError::InvalidModifier);
+        }
+        Ok(Extension {
+            singleton,
+            subtags: subtags.iter().map(|s| s.to_ascii_lowercase()).collect(),
+        })
+    }
+
+    /// Return the singleton character identifying this extension.
+    pub fn singleton(&self) -> char {
+        self.singleton
+    }
+
+    /// Return the subtags following the singleton.
+    pub fn subtags(&self) -> Vec<String> {
+        self.subtags.clone()
+    }
+}
+
+impl Display for Extension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.singleton, self.subtags.join("-"))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_alpha(s: &str, min: usize, max: usize) -> bool {
+    s.len() >= min && s.len() <= max && s.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_alphanumeric(s: &str, min: usize, max: usize) -> bool {
+    s.len() >= min && s.len() <= max && s.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_language(s: &str) -> bool {
+    is_alpha(s, 2, 8)
+}
+
+fn is_extended_language(s: &str) -> bool {
+    is_alpha(s, 3, 3)
+}
+
+fn is_script(s: &str) -> bool {
+    is_alpha(s, 4, 4)
+}
+
+fn is_region(s: &str) -> bool {
+    is_alpha(s, 2, 2) || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn is_variant(s: &str) -> bool {
+    is_alphanumeric(s, 5, 8)
+        || (is_alphanumeric(s, 4, 4) && s.chars().next().unwrap().is_ascii_digit())
+}
+
+fn singleton(s: &str) -> Option<char> {
+    if s.len() == 1 && s != PRIVATE_USE && is_alphanumeric(s, 1, 1) {
+        s.chars().next()
+    } else {
+        None
+    }
+}
+
+fn is_extension(s: &str) -> bool {
+    is_alphanumeric(s, 2, 8)
+}
+
+fn is_private_use(s: &str) -> bool {
+    is_alphanumeric(s, 1, 8)
+}
+
+fn title_case(s: &str) -> String {
+    s.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if i == 0 {
+                c.to_ascii_uppercase()
+            } else {
+                c.to_ascii_lowercase()
+            }
+        })
+        .collect()
+}
+
+fn has_duplicates(values: &[String]) -> bool {
+    values
+        .iter()
+        .enumerate()
+        .any(|(i, v)| values[i + 1..].contains(v))
+}
+
+fn unexpected_subtag(subtag: &str) -> ParseErrorKind {
+    if is_script(subtag) {
+        ParseErrorKind::InvalidModifier
+    } else if is_region(subtag) {
+        ParseErrorKind::InvalidTerritoryCode
+    } else if subtag.len() == 1 {
+        ParseErrorKind::InvalidExtension
+    } else {
+        ParseErrorKind::InvalidVariant
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use proptest::prelude::*;
+
+    use crate::string::{ParseError, ParseErrorKind};
+    use crate::{LanguageTag, LocaleError, LocaleIdentifier};
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_bad_constructor() {
+        assert_eq!(
+            LanguageTag::new("e".to_string()),
+            Err(LocaleError::InvalidLanguageCode)
+        );
+        assert_eq!(
+            LanguageTag::new("en-US".to_string()),
+            Err(LocaleError::InvalidLanguageCode)
+        );
+    }
+
+    #[test]
+    fn test_unsupported_code_set() {
+        assert_eq!(
+            LanguageTag::new("en".to_string())
+                .unwrap()
+                .with_code_set("UTF-8".to_string()),
+            Err(LocaleError::Unsupported)
+        );
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_constructor() {
+        let tag = LanguageTag::new("EN".to_string()).unwrap();
+        assert_eq!(tag.language_code(), "en".to_string());
+        assert_eq!(tag.territory(), None);
+        assert_eq!(tag.code_set(), None);
+        assert_eq!(tag.modifier(), None);
+    }
+
+    #[test]
+    fn test_with_builders() {
+        let tag = LanguageTag::new("zh".to_string())
+            .unwrap()
+            .with_territory("tw".to_string())
+            .unwrap()
+            .with_modifier("Hant".to_string())
+            .unwrap();
+        assert_eq!(tag.territory(), Some("TW".to_string()));
+        assert_eq!(tag.script(), Some("Hant".to_string()));
+        assert_eq!(tag.modifier(), Some("Hant".to_string()));
+        assert_eq!(tag.to_string(), "zh-Hant-TW");
+
+        let tag = LanguageTag::new("ca".to_string())
+            .unwrap()
+            .with_territory("ES".to_string())
+            .unwrap()
+            .with_modifier("valencia".to_string())
+            .unwrap();
+        assert_eq!(tag.variants(), vec!["valencia".to_string()]);
+        assert_eq!(tag.to_string(), "ca-ES-valencia");
+    }
+
+    #[test]
+    fn test_with_modifier_not_script() {
+        let tag = LanguageTag::new("en".to_string()).unwrap();
+        for modifier in &["euro", "test", "Test", "hant", "LATN"] {
+            assert_eq!(
+                tag.with_modifier(modifier.to_string()),
+                Err(LocaleError::InvalidModifier),
+                "{}",
+                modifier
+            );
+        }
+        assert_eq!(
+            tag.with_modifier("Qaab".to_string()).unwrap().script(),
+            Some("Qaab".to_string())
+        );
+    }
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_from_str_simple() {
+        let tag = LanguageTag::from_str("en-US").unwrap();
+        assert_eq!(tag.language_code(), "en");
+        assert_eq!(tag.territory(), Some("US".to_string()));
+        assert_eq!(tag.to_string(), "en-US");
+    }
+
+    #[test]
+    fn test_from_str_full() {
+        let tag =
+            LanguageTag::from_str("zh-yue-hant-hk-1996-fonipa-u-co-pinyin-t-en-x-private").unwrap();
+        assert_eq!(tag.language_code(), "zh");
+        assert_eq!(tag.extended_languages(), vec!["yue".to_string()]);
+        assert_eq!(tag.script(), Some("Hant".to_string()));
+        assert_eq!(tag.territory(), Some("HK".to_string()));
+        assert_eq!(
+            tag.variants(),
+            vec!["1996".to_string(), "fonipa".to_string()]
+        );
+        assert_eq!(tag.extensions().len(), 2);
+        assert_eq!(tag.extensions()[0].singleton(), 'u');
+        assert_eq!(
+            tag.extensions()[0].subtags(),
+            vec!["co".to_string(), "pinyin".to_string()]
+        );
+        assert_eq!(tag.private_use(), vec!["private".to_string()]);
+        assert_eq!(
+            tag.to_string(),
+            "zh-yue-Hant-HK-1996-fonipa-u-co-pinyin-t-en-x-private"
+        );
+    }
+
+    #[test]
+    fn test_from_str_numeric_region() {
+        let tag = LanguageTag::from_str("es-419").unwrap();
+        assert_eq!(tag.territory(), Some("419".to_string()));
+    }
+
+    #[test]
+    fn test_from_str_grandfathered() {
+        let tag = LanguageTag::from_str("I-KLINGON").unwrap();
+        assert!(tag.is_grandfathered());
+        assert_eq!(tag.language_code(), "i-klingon");
+        assert_eq!(tag.to_string(), "i-klingon");
+
+        let tag = LanguageTag::from_str("zh-min-nan").unwrap();
+        assert!(tag.is_grandfathered());
+        assert_eq!(tag.to_string(), "zh-min-nan");
+    }
+
+    #[test]
+    fn test_from_str_private_use() {
+        let tag = LanguageTag::from_str("x-whatever").unwrap();
+        assert!(tag.is_private_use());
+        assert_eq!(tag.private_use(), vec!["whatever".to_string()]);
+        assert_eq!(tag.to_string(), "x-whatever");
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        let error = |s, kind, span| Err(ParseError::new(kind, s, span));
+        assert_eq!(
+            LanguageTag::from_str(""),
+            error("", ParseErrorKind::EmptyString, 0..0)
+        );
+        assert_eq!(
+            LanguageTag::from_str("1234"),
+            error("1234", ParseErrorKind::InvalidLanguageCode, 0..4)
+        );
+        assert_eq!(
+            LanguageTag::from_str("en--US"),
+            error("en--US", ParseErrorKind::InvalidVariant, 3..3)
+        );
+        assert_eq!(
+            LanguageTag::from_str("en-US-US"),
+            error("en-US-US", ParseErrorKind::InvalidTerritoryCode, 6..8)
+        );
+        assert_eq!(
+            LanguageTag::from_str("de-1901-1901"),
+            error("de-1901-1901", ParseErrorKind::InvalidVariant, 8..12)
+        );
+        assert_eq!(
+            LanguageTag::from_str("en-a-bb-a-cc"),
+            error("en-a-bb-a-cc", ParseErrorKind::InvalidExtension, 8..9)
+        );
+        assert_eq!(
+            LanguageTag::from_str("en-a"),
+            error("en-a", ParseErrorKind::InvalidExtension, 3..4)
+        );
+        assert_eq!(
+            LanguageTag::from_str("en-x"),
+            error("en-x", ParseErrorKind::InvalidPrivateUse, 3..4)
+        );
+        assert_eq!(
+            LanguageTag::from_str("en-x-abcdefghi"),
+            error("en-x-abcdefghi", ParseErrorKind::InvalidPrivateUse, 5..14)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_from_str_never_panics(s in "\\PC*") {
+            let _ = LanguageTag::from_str(&s);
+        }
+
+        #[test]
+        fn prop_from_str_display_round_trip(s in "[a-z]{2,3}(-[A-Z][a-z]{3})?(-[A-Z]{2})?(-[a-z]{5,8})?(-u(-[a-z]{2,8}){1,3})?(-x-[a-z]{1,8})?") {
+            let tag = LanguageTag::from_str(&s).unwrap();
+            prop_assert_eq!(tag.to_string(), s);
+        }
+    }
+}